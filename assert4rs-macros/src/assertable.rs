@@ -0,0 +1,117 @@
+//! `#[derive(Assertable)]`: a `{Name}Fields` trait on `Assert<Name>` and
+//! `Assert<&Name>` with one accessor per named field, and a private
+//! `{Name}PrivateFields` trait for fields less visible than the struct.
+
+use proc_macro::TokenStream;
+
+use crate::compile_error;
+use crate::item::{self, Body, Field, Fields, Item};
+
+const UNSUPPORTED: &str = "Assertable can only be derived for structs with named fields";
+
+pub fn derive(input: TokenStream) -> TokenStream {
+    let Some(input) = item::parse(input) else {
+        return compile_error(UNSUPPORTED);
+    };
+    let Body::Struct(Fields::Named(fields)) = &input.body else {
+        return compile_error(UNSUPPORTED);
+    };
+    // Fields less visible than the struct get their accessors in a
+    // private trait, so the public one doesn't expose them, or their types.
+    let (visible, hidden): (Vec<&Field>, Vec<&Field>) = fields
+        .iter()
+        .partition(|field| field.visibility == "pub" || field.visibility == input.visibility);
+    let mut generated = accessors(
+        &input,
+        &format!("{}Fields", input.name),
+        &input.visibility,
+        &visible,
+    );
+    if !hidden.is_empty() {
+        generated.push_str(&accessors(
+            &input,
+            &format!("{}PrivateFields", input.name),
+            "",
+            &hidden,
+        ));
+    }
+    generated.parse().expect("valid generated code")
+}
+
+/// A trait named `trait_name` with an accessor per field of `fields`, and
+/// its impls for `Assert<Name>` and `Assert<&Name>`.
+fn accessors(input: &Item, trait_name: &str, visibility: &str, fields: &[&Field]) -> String {
+    let root = snake_case(&input.name);
+    let name = &input.name;
+    let item_ty = input.ty();
+    let where_clause = &input.where_clause;
+    let parameters = input.parameters.join(", ");
+    let by_reference = std::iter::once("'__assert4rs".to_string())
+        .chain(input.parameters.iter().cloned())
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let mut declarations = String::new();
+    let mut owned = String::new();
+    let mut borrowed = String::new();
+    for Field {
+        name: field, ty, ..
+    } in fields
+    {
+        let label = field.trim_start_matches("r#");
+        let assoc = camel_case(label);
+        declarations.push_str(&format!(
+            "#[doc = \"Assert on the `{label}` field, labelled `{root}.{label}` \
+             or with the label path extended by `.{label}`.\"]\
+             fn {field}(self) -> ::assert4rs::Assert<Self::{assoc}>;\
+             #[doc = \"Type of the `{label}` field.\"]\
+             type {assoc};"
+        ));
+        owned.push_str(&format!(
+            "type {assoc} = {ty};\
+             fn {field}(self) -> ::assert4rs::Assert<{ty}> {{\
+                 ::assert4rs::__private::field(self, \"{root}\", \"{label}\", |value| value.{field})\
+             }}"
+        ));
+        borrowed.push_str(&format!(
+            "type {assoc} = &'__assert4rs {ty};\
+             fn {field}(self) -> ::assert4rs::Assert<&'__assert4rs {ty}> {{\
+                 ::assert4rs::__private::field(self, \"{root}\", \"{label}\", |value| &value.{field})\
+             }}"
+        ));
+    }
+    format!(
+        "#[doc = \"Field accessors for assertions on [{name}], derived with `Assertable`.\"]\
+         #[allow(dead_code)]\
+         {visibility} trait {trait_name} {{ {declarations} }}\
+         impl<{parameters}> {trait_name} for ::assert4rs::Assert<{item_ty}> {where_clause} {{ {owned} }}\
+         impl<{by_reference}> {trait_name} for ::assert4rs::Assert<&'__assert4rs {item_ty}> {where_clause} {{ {borrowed} }}"
+    )
+}
+
+fn snake_case(name: &str) -> String {
+    let mut snake = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() {
+            if i > 0 {
+                snake.push('_');
+            }
+            snake.extend(c.to_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+    snake
+}
+
+fn camel_case(name: &str) -> String {
+    name.split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect()
+}
@@ -0,0 +1,156 @@
+//! `#[derive(Diff)]`: compares structs field by field and enums variant
+//! by variant, prefixing the changes found in each field with its name.
+
+use proc_macro::TokenStream;
+
+use crate::compile_error;
+use crate::item::{self, Body, Fields};
+
+pub fn derive(input: TokenStream) -> TokenStream {
+    let Some(input) = item::parse(input) else {
+        return compile_error("Diff can only be derived for structs and enums");
+    };
+    let changes = body(
+        &input.body,
+        |actual, expected, segment| {
+            format!("changes.extend(comparison.diff_at(\"{segment}\", {actual}, {expected}));")
+        },
+        "changes.push(::assert4rs::Change::changed(self, other))",
+    );
+    let equal = body(
+        &input.body,
+        |actual, expected, segment| {
+            format!(
+                "if !comparison.eq_at(\"{segment}\", {actual}, {expected}) {{\
+                     return false;\
+                 }}"
+            )
+        },
+        "return false",
+    );
+    // A function registered for the item's own type takes over from
+    // comparing it field by field; `Comparison::custom` needs the type to
+    // be `'static`, which only an item without generics is sure to be.
+    let (custom, custom_eq) = if input.parameters.is_empty() {
+        (
+            "if let ::std::option::Option::Some(changes) = comparison.custom(self, other) {\
+                 return changes;\
+             }",
+            "if let ::std::option::Option::Some(equal) = comparison.custom_eq(self, other) {\
+                 return equal;\
+             }",
+        )
+    } else {
+        ("", "")
+    };
+    let bounds: Vec<String> = input
+        .type_parameters
+        .iter()
+        .map(|parameter| format!("{parameter}: ::assert4rs::Diff"))
+        .collect();
+    format!(
+        "impl<{}> ::assert4rs::Diff for {} {} {{\
+             fn diff_with(\
+                 &self,\
+                 other: &Self,\
+                 comparison: &::assert4rs::Comparison,\
+             ) -> ::std::vec::Vec<::assert4rs::Change> {{\
+                 {custom}\
+                 let mut changes = ::std::vec::Vec::new();\
+                 {changes}\
+                 changes\
+             }}\
+             fn eq_with(&self, other: &Self, comparison: &::assert4rs::Comparison) -> bool {{\
+                 {custom_eq}\
+                 {equal}\
+                 true\
+             }}\
+         }}",
+        input.parameters.join(", "),
+        input.ty(),
+        input.where_clause_with(&bounds),
+    )
+    .parse()
+    .expect("valid generated code")
+}
+
+/// Code comparing the fields of `self` and `other` one by one with
+/// `compare`, given the expressions for the actual and expected field and
+/// its path segment, and running `otherwise` if they're different enum
+/// variants.
+fn body(body: &Body, compare: impl Fn(&str, &str, &str) -> String, otherwise: &str) -> String {
+    match body {
+        Body::Struct(fields) => {
+            let mut body = String::new();
+            for (segment, accessor) in segments(fields) {
+                body.push_str(&compare(
+                    &format!("&self.{accessor}"),
+                    &format!("&other.{accessor}"),
+                    &segment,
+                ));
+            }
+            body
+        }
+        Body::Enum(variants) => {
+            let mut arms = String::new();
+            for variant in variants {
+                let name = &variant.name;
+                let mut compared = String::new();
+                let segments = segments(&variant.fields);
+                for (i, (segment, _)) in segments.iter().enumerate() {
+                    compared.push_str(&compare(
+                        &format!("__actual_{i}"),
+                        &format!("__expected_{i}"),
+                        segment,
+                    ));
+                }
+                let pattern = |prefix: &str| {
+                    let bindings = segments
+                        .iter()
+                        .enumerate()
+                        .map(|(i, (_, accessor))| match &variant.fields {
+                            Fields::Named(_) => format!("{accessor}: {prefix}_{i}"),
+                            _ => format!("{prefix}_{i}"),
+                        })
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    match &variant.fields {
+                        Fields::Named(_) => format!("Self::{name} {{ {bindings} }}"),
+                        Fields::Unnamed(_) => format!("Self::{name}({bindings})"),
+                        Fields::Unit => format!("Self::{name}"),
+                    }
+                };
+                arms.push_str(&format!(
+                    "({}, {}) => {{ {compared} }}",
+                    pattern("__actual"),
+                    pattern("__expected")
+                ));
+            }
+            format!(
+                "#[allow(unreachable_patterns)]\
+                 match (self, other) {{\
+                     {arms}\
+                     _ => {otherwise},\
+                 }}"
+            )
+        }
+    }
+}
+
+/// The path segment and accessor of each field, e.g. `.name` and `name`,
+/// or `.0` and `0`.
+fn segments(fields: &Fields) -> Vec<(String, String)> {
+    match fields {
+        Fields::Named(fields) => fields
+            .iter()
+            .map(|field| {
+                let name = &field.name;
+                (format!(".{}", name.trim_start_matches("r#")), name.clone())
+            })
+            .collect(),
+        Fields::Unnamed(types) => (0..types.len())
+            .map(|i| (format!(".{i}"), i.to_string()))
+            .collect(),
+        Fields::Unit => Vec::new(),
+    }
+}
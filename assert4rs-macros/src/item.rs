@@ -0,0 +1,290 @@
+//! Reading the struct or enum a derive is applied to.
+//!
+//! Like [check!](crate::check), items are read by hand rather than with a
+//! full Rust parser; only what's needed to name the item, its generics,
+//! its variants and its fields is picked out, and the rest (types, bounds,
+//! attributes) is passed through as is.
+
+use proc_macro::{Delimiter, Spacing, TokenStream, TokenTree};
+use std::iter::Peekable;
+
+pub struct Item {
+    pub visibility: String,
+    pub name: String,
+    /// Generic parameters as declared, without defaults.
+    pub parameters: Vec<String>,
+    /// Generic arguments naming the parameters, e.g. `'a`, `T`, `N`.
+    pub arguments: Vec<String>,
+    /// The names of the generic type parameters, leaving out lifetimes
+    /// and consts.
+    pub type_parameters: Vec<String>,
+    /// The `where` clause, if any, with its `where`.
+    pub where_clause: String,
+    pub body: Body,
+}
+
+pub enum Body {
+    Struct(Fields),
+    Enum(Vec<Variant>),
+}
+
+pub struct Variant {
+    pub name: String,
+    pub fields: Fields,
+}
+
+pub enum Fields {
+    Named(Vec<Field>),
+    /// The types of the fields of a tuple struct or variant.
+    Unnamed(Vec<String>),
+    Unit,
+}
+
+pub struct Field {
+    pub visibility: String,
+    pub name: String,
+    pub ty: String,
+}
+
+impl Item {
+    /// The item's type, e.g. `Page<'a, T>`.
+    pub fn ty(&self) -> String {
+        format!("{}<{}>", self.name, self.arguments.join(", "))
+    }
+
+    /// The `where` clause extended with `bounds`.
+    pub fn where_clause_with(&self, bounds: &[String]) -> String {
+        if bounds.is_empty() {
+            return self.where_clause.clone();
+        }
+        let mut clause = match self.where_clause.trim_end() {
+            "" => String::from("where"),
+            existing if existing.ends_with(',') => existing.to_string(),
+            existing => format!("{existing},"),
+        };
+        clause.push(' ');
+        clause.push_str(&bounds.join(", "));
+        clause
+    }
+}
+
+/// Reads a struct or enum, or returns `None` for anything else.
+pub fn parse(input: TokenStream) -> Option<Item> {
+    let mut tokens = input.into_iter().peekable();
+    skip_attributes(&mut tokens);
+    let visibility = take_visibility(&mut tokens);
+    let is_enum = match tokens.next()? {
+        TokenTree::Ident(ident) if ident.to_string() == "struct" => false,
+        TokenTree::Ident(ident) if ident.to_string() == "enum" => true,
+        _ => return None,
+    };
+    let name = match tokens.next()? {
+        TokenTree::Ident(ident) => ident.to_string(),
+        _ => return None,
+    };
+
+    let mut parameters = Vec::new();
+    let mut arguments = Vec::new();
+    let mut type_parameters = Vec::new();
+    if matches!(tokens.peek(), Some(TokenTree::Punct(p)) if p.as_char() == '<') {
+        tokens.next();
+        let mut generics = Vec::new();
+        let mut depth = 0;
+        for token in tokens.by_ref() {
+            match &token {
+                TokenTree::Punct(p) if p.as_char() == '<' => depth += 1,
+                TokenTree::Punct(p) if p.as_char() == '>' && !is_arrow(&generics) => {
+                    if depth == 0 {
+                        break;
+                    }
+                    depth -= 1;
+                }
+                _ => {}
+            }
+            generics.push(token);
+        }
+        for parameter in split_top_level(generics, ',') {
+            let (argument, declaration, is_type) = generic_parameter(parameter)?;
+            if is_type {
+                type_parameters.push(argument.clone());
+            }
+            arguments.push(argument);
+            parameters.push(declaration);
+        }
+    }
+
+    // The `where` clause comes before the fields of a struct with named
+    // fields, but after those of a tuple struct.
+    let mut where_clause = TokenStream::new();
+    let mut body = None;
+    for token in tokens {
+        match token {
+            TokenTree::Group(group) if body.is_none() && group.delimiter() == Delimiter::Brace => {
+                body = Some(if is_enum {
+                    Body::Enum(variants(group.stream())?)
+                } else {
+                    Body::Struct(Fields::Named(named_fields(group.stream())?))
+                });
+            }
+            TokenTree::Group(group)
+                if body.is_none() && !is_enum && group.delimiter() == Delimiter::Parenthesis =>
+            {
+                body = Some(Body::Struct(Fields::Unnamed(unnamed_fields(
+                    group.stream(),
+                ))));
+            }
+            TokenTree::Punct(p) if p.as_char() == ';' => {
+                body.get_or_insert(Body::Struct(Fields::Unit));
+            }
+            token => where_clause.extend([token]),
+        }
+    }
+    Some(Item {
+        visibility,
+        name,
+        parameters,
+        arguments,
+        type_parameters,
+        where_clause: where_clause.to_string(),
+        body: body?,
+    })
+}
+
+fn variants(tokens: TokenStream) -> Option<Vec<Variant>> {
+    split_top_level(tokens.into_iter().collect(), ',')
+        .into_iter()
+        .map(|variant| {
+            let mut tokens = variant.into_iter().peekable();
+            skip_attributes(&mut tokens);
+            let name = match tokens.next()? {
+                TokenTree::Ident(ident) => ident.to_string(),
+                _ => return None,
+            };
+            let fields = match tokens.next() {
+                Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Brace => {
+                    Fields::Named(named_fields(group.stream())?)
+                }
+                Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis => {
+                    Fields::Unnamed(unnamed_fields(group.stream()))
+                }
+                // A discriminant, or nothing.
+                _ => Fields::Unit,
+            };
+            Some(Variant { name, fields })
+        })
+        .collect()
+}
+
+fn named_fields(tokens: TokenStream) -> Option<Vec<Field>> {
+    split_top_level(tokens.into_iter().collect(), ',')
+        .into_iter()
+        .map(|field| {
+            let mut tokens = field.into_iter().peekable();
+            skip_attributes(&mut tokens);
+            let visibility = take_visibility(&mut tokens);
+            let name = match tokens.next()? {
+                TokenTree::Ident(ident) => ident.to_string(),
+                _ => return None,
+            };
+            match tokens.next()? {
+                TokenTree::Punct(p) if p.as_char() == ':' => {}
+                _ => return None,
+            }
+            Some(Field {
+                visibility,
+                name,
+                ty: tokens.collect::<TokenStream>().to_string(),
+            })
+        })
+        .collect()
+}
+
+fn unnamed_fields(tokens: TokenStream) -> Vec<String> {
+    split_top_level(tokens.into_iter().collect(), ',')
+        .into_iter()
+        .map(|field| {
+            let mut tokens = field.into_iter().peekable();
+            skip_attributes(&mut tokens);
+            take_visibility(&mut tokens);
+            tokens.collect::<TokenStream>().to_string()
+        })
+        .collect()
+}
+
+/// Splits a generic parameter into the argument naming it, its
+/// declaration without a default, e.g. `T` and `T: Clone` for
+/// `T: Clone = u32`, and whether it's a type parameter.
+fn generic_parameter(tokens: Vec<TokenTree>) -> Option<(String, String, bool)> {
+    let (argument, is_type) = match tokens.first()? {
+        TokenTree::Punct(p) if p.as_char() == '\'' => (format!("'{}", tokens.get(1)?), false),
+        TokenTree::Ident(ident) if ident.to_string() == "const" => {
+            (tokens.get(1)?.to_string(), false)
+        }
+        token => (token.to_string(), true),
+    };
+    let declaration = split_top_level(tokens, '=').into_iter().next()?;
+    Some((
+        argument,
+        declaration.into_iter().collect::<TokenStream>().to_string(),
+        is_type,
+    ))
+}
+
+fn skip_attributes(tokens: &mut Peekable<impl Iterator<Item = TokenTree>>) {
+    while matches!(tokens.peek(), Some(TokenTree::Punct(p)) if p.as_char() == '#') {
+        tokens.next();
+        tokens.next();
+    }
+}
+
+fn take_visibility(tokens: &mut Peekable<impl Iterator<Item = TokenTree>>) -> String {
+    if !matches!(tokens.peek(), Some(TokenTree::Ident(ident)) if ident.to_string() == "pub") {
+        return String::new();
+    }
+    let mut visibility = tokens.next().unwrap().to_string();
+    if let Some(TokenTree::Group(group)) = tokens.peek()
+        && group.delimiter() == Delimiter::Parenthesis
+    {
+        visibility.push_str(&tokens.next().unwrap().to_string());
+    }
+    visibility
+}
+
+/// Splits `tokens` at each `separator` outside of angle brackets,
+/// dropping empty parts such as the one after a trailing separator.
+fn split_top_level(tokens: Vec<TokenTree>, separator: char) -> Vec<Vec<TokenTree>> {
+    let mut parts = vec![Vec::new()];
+    let mut depth = 0usize;
+    for token in tokens {
+        if let TokenTree::Punct(p) = &token {
+            let current = parts.last().unwrap();
+            match p.as_char() {
+                '<' => depth += 1,
+                '>' if !is_arrow(current) => depth = depth.saturating_sub(1),
+                c if c == separator
+                    && depth == 0
+                    && !(c == '=' && is_compound(current, p.spacing())) =>
+                {
+                    parts.push(Vec::new());
+                    continue;
+                }
+                _ => {}
+            }
+        }
+        parts.last_mut().unwrap().push(token);
+    }
+    parts.retain(|part| !part.is_empty());
+    parts
+}
+
+/// Whether a `>` following `tokens` is the end of a `->`.
+fn is_arrow(tokens: &[TokenTree]) -> bool {
+    matches!(tokens.last(), Some(TokenTree::Punct(p)) if p.as_char() == '-' && p.spacing() == Spacing::Joint)
+}
+
+/// Whether a `=` following `tokens` is part of `==`, `<=` and the like,
+/// rather than a default.
+fn is_compound(tokens: &[TokenTree], spacing: Spacing) -> bool {
+    spacing == Spacing::Joint
+        || matches!(tokens.last(), Some(TokenTree::Punct(p)) if p.spacing() == Spacing::Joint)
+}
@@ -0,0 +1,904 @@
+//! Procedural macros for [assert4rs](https://docs.rs/assert4rs), enabled
+//! with its `macros` feature and re-exported from there.
+//!
+//! The expression passed to [check!] is split into its sub-expressions
+//! by hand rather than with a full Rust parser: binary operators, `!`
+//! and parentheses are taken apart, everything else (method calls,
+//! field accesses, indexing, closures, ...) is kept whole and shown as
+//! one value. Expressions it doesn't understand are checked as a whole.
+//!
+//! [`#[derive(Assertable)]`](derive@Assertable) generates field accessors
+//! for assertions on a struct, and [`#[derive(Diff)]`](derive@Diff)
+//! compares structs and enums field by field.
+
+use proc_macro::{Delimiter, Group, Literal, Spacing, Span, TokenStream, TokenTree};
+use std::collections::VecDeque;
+use std::mem;
+
+mod assertable;
+mod diff;
+mod item;
+
+/// Assert that a boolean expression holds, showing the value of each of
+/// its sub-expressions under the expression when it doesn't.
+///
+/// ```
+/// # use assert4rs::check;
+/// let a = vec![1, 2, 3];
+/// let b = vec![1];
+/// check!(a.len() == b.len() + 2);
+/// ```
+///
+/// ```should_panic
+/// # use assert4rs::check;
+/// let a = vec![1, 2, 3];
+/// let b = vec![1];
+/// check!(a.len() == b.len() + 1);
+/// ```
+///
+/// fails with
+///
+/// ```text
+/// Assertion failed: `(a.len() == b.len() + 1)`
+///   a.len() == b.len() + 1
+///   |       |  |       | |
+///   3       |  1       2 1
+///           false
+/// ```
+///
+/// Values without a [Debug](std::fmt::Debug) implementation are shown
+/// as `<?>`. `&&` and `||` short-circuit as usual, so values that
+/// weren't evaluated are left out. Values are only formatted when the
+/// check fails, so temporaries in compared values live until it's
+/// decided. Inside `Assert::soft` and `Assert::check`, a failed check is
+/// collected like a failed assertion.
+#[proc_macro]
+pub fn check(input: TokenStream) -> TokenStream {
+    let tokens: Vec<TokenTree> = input.into_iter().collect();
+    if tokens.is_empty() {
+        return compile_error("check! expects a boolean expression");
+    }
+    if has_top_level_comma(&tokens) {
+        return compile_error("check! expects a single boolean expression");
+    }
+    let mut root = parse(tokens);
+    let mut text = String::new();
+    layout(&mut root, &mut text);
+
+    let mut generator = Generator::new();
+    let result = generator.value(&root, true, Some(true));
+    let mut tail = code("if !");
+    tail.extend([parenthesized(result)]);
+    let mut fail = code("__recorder.fail");
+    fail.extend([parenthesized(TokenStream::from(TokenTree::Literal(
+        Literal::string(&text),
+    )))]);
+    fail.extend(code(";"));
+    tail.extend([braced(fail)]);
+    let mut expansion = code(
+        "use ::assert4rs::__private::{CopyValue as _, DebugValue as _, NoDebugValue as _};\
+         let mut __recorder = ::assert4rs::__private::Recorder::default();",
+    );
+    expansion.extend(generator.finish(tail));
+    braced(expansion).into()
+}
+
+/// Navigate from an assertion on a struct to assertions on its fields.
+///
+/// Generates a `{Name}Fields` trait implemented for `Assert<Name>` and
+/// `Assert<&Name>`, with a method per named field returning an `Assert`
+/// of the field, or of a reference to it. The field is added to the label
+/// path, starting from the struct's name in snake case when there's no
+/// label yet, so a failure names the field it's about:
+///
+/// ```
+/// use assert4rs::{Assert, Assertable};
+///
+/// #[derive(Debug, Assertable)]
+/// struct Address { city: String }
+///
+/// #[derive(Debug, Assertable)]
+/// struct User { name: String, address: Address }
+///
+/// let user = User { name: "Ann".into(), address: Address { city: "Utrecht".into() } };
+/// Assert::that(&user).name().is("Ann");
+/// Assert::that(user).address().city().is("Utrecht");
+/// ```
+///
+/// ```should_panic
+/// # use assert4rs::{Assert, Assertable};
+/// # #[derive(Debug, Assertable)]
+/// # struct Address { city: String }
+/// # #[derive(Debug, Assertable)]
+/// # struct User { name: String, address: Address }
+/// // Assertion failed for `user.address.city`: `(actual == expected)`
+/// let user = User { name: "Ann".into(), address: Address { city: "Utrecht".into() } };
+/// Assert::that(user).address().city().is("Amsterdam");
+/// ```
+///
+/// The trait is declared next to the struct, with the struct's
+/// visibility; import it to use the accessors elsewhere. Fields less
+/// visible than the struct get their accessors in a private
+/// `{Name}PrivateFields` trait instead, so they can only be used where
+/// the struct is declared.
+#[proc_macro_derive(Assertable)]
+pub fn derive_assertable(input: TokenStream) -> TokenStream {
+    assertable::derive(input)
+}
+
+/// Implement `assert4rs::Diff` by comparing field by field.
+///
+/// Structs compare each of their fields, enums the fields of matching
+/// variants, and the changes found in a field are prefixed with its name
+/// (`.name`, or `.0` for tuple fields). Values of different variants are
+/// one change as a whole. Type parameters are required to implement
+/// `Diff` too.
+///
+/// ```
+/// use assert4rs::{Assert, Diff};
+///
+/// #[derive(Debug, PartialEq, Diff)]
+/// struct Line { sku: String, qty: u32 }
+///
+/// #[derive(Debug, PartialEq, Diff)]
+/// enum Status { Open, Shipped { lines: Vec<Line> } }
+///
+/// let status = |qty| Status::Shipped { lines: vec![Line { sku: "apple".into(), qty }] };
+/// Assert::that(status(2)).is_eq_to(status(2));
+/// ```
+///
+/// ```should_panic
+/// # use assert4rs::{Assert, Diff};
+/// # #[derive(Debug, PartialEq, Diff)]
+/// # struct Line { sku: String, qty: u32 }
+/// # #[derive(Debug, PartialEq, Diff)]
+/// # enum Status { Open, Shipped { lines: Vec<Line> } }
+/// # let status = |qty| Status::Shipped { lines: vec![Line { sku: "apple".into(), qty }] };
+/// // Assertion failed: `(actual.is_eq_to(expected))`
+/// //   ...
+/// //   Differences:
+/// //     .lines[0].qty: 2 != 3
+/// Assert::that(status(2)).is_eq_to(status(3));
+/// ```
+#[proc_macro_derive(Diff)]
+pub fn derive_diff(input: TokenStream) -> TokenStream {
+    diff::derive(input)
+}
+
+/// A sub-expression of a checked expression. `column` is where it's
+/// shown in the expression text: the start of a leaf, or the operator of
+/// the others.
+enum Node {
+    Leaf {
+        tokens: Vec<TokenTree>,
+        column: usize,
+    },
+    Not {
+        operand: Box<Node>,
+        column: usize,
+    },
+    Binary {
+        operator: String,
+        lhs: Box<Node>,
+        rhs: Box<Node>,
+        column: usize,
+    },
+    Paren {
+        inner: Box<Node>,
+    },
+}
+
+/// A token, or an operator put together from the [Spacing::Joint]
+/// punctuation it arrives as.
+enum Piece {
+    Tree(TokenTree),
+    Operator(String, Vec<TokenTree>),
+}
+
+impl Piece {
+    fn is(&self, operator: &str) -> bool {
+        matches!(self, Piece::Operator(op, _) if op == operator)
+    }
+
+    fn is_ident(&self, name: &str) -> bool {
+        matches!(self, Piece::Tree(TokenTree::Ident(ident)) if ident.to_string() == name)
+    }
+
+    fn into_tokens(self) -> Vec<TokenTree> {
+        match self {
+            Piece::Tree(tree) => vec![tree],
+            Piece::Operator(_, tokens) => tokens,
+        }
+    }
+}
+
+/// Multi-character operators, longest first so they're matched greedily.
+const COMPOUND_OPERATORS: &[&str] = &[
+    "<<=", ">>=", "...", "..=", "::", "->", "=>", "==", "!=", "<=", ">=", "&&", "||", "+=", "-=",
+    "*=", "/=", "%=", "^=", "&=", "|=", "<<", ">>", "..",
+];
+
+/// Keywords that start an expression that runs to the end of the input,
+/// which is then kept whole.
+const GREEDY_KEYWORDS: &[&str] = &[
+    "async", "break", "continue", "for", "if", "let", "loop", "match", "move", "return", "unsafe",
+    "while",
+];
+
+/// Binding strength of the binary operators that are taken apart.
+fn precedence(operator: &str) -> Option<u8> {
+    Some(match operator {
+        "*" | "/" | "%" => 10,
+        "+" | "-" => 9,
+        "<<" | ">>" => 8,
+        "&" => 7,
+        "^" => 6,
+        "|" => 5,
+        "==" | "!=" | "<" | "<=" | ">" | ">=" => 4,
+        "&&" => 3,
+        "||" => 2,
+        _ => return None,
+    })
+}
+
+fn is_comparison(operator: &str) -> bool {
+    precedence(operator) == Some(4)
+}
+
+fn has_top_level_comma(tokens: &[TokenTree]) -> bool {
+    tokens
+        .iter()
+        .any(|token| matches!(token, TokenTree::Punct(punct) if punct.as_char() == ','))
+}
+
+fn pieces(tokens: Vec<TokenTree>) -> VecDeque<Piece> {
+    let mut pieces = VecDeque::new();
+    let mut run: Vec<TokenTree> = Vec::new();
+    let flush = |run: &mut Vec<TokenTree>, pieces: &mut VecDeque<Piece>| {
+        let mut rest = &run[..];
+        while !rest.is_empty() {
+            let chars: String = rest.iter().map(|token| token.to_string()).collect();
+            let len = COMPOUND_OPERATORS
+                .iter()
+                .find(|op| chars.starts_with(**op))
+                .map_or(1, |op| op.len());
+            pieces.push_back(Piece::Operator(
+                chars[..len].to_string(),
+                rest[..len].to_vec(),
+            ));
+            rest = &rest[len..];
+        }
+        run.clear();
+    };
+    for token in tokens {
+        match &token {
+            TokenTree::Punct(punct) => {
+                let joint = punct.spacing() == Spacing::Joint;
+                run.push(token);
+                if !joint {
+                    flush(&mut run, &mut pieces);
+                }
+            }
+            _ => {
+                flush(&mut run, &mut pieces);
+                pieces.push_back(Piece::Tree(token));
+            }
+        }
+    }
+    flush(&mut run, &mut pieces);
+    pieces
+}
+
+/// An operand between binary operators, with the number of `!` before it.
+struct Operand {
+    nots: usize,
+    tokens: Vec<TokenTree>,
+}
+
+/// Parses `tokens` into a tree, or a single leaf if they contain
+/// something [scan] doesn't understand.
+fn parse(tokens: Vec<TokenTree>) -> Node {
+    match scan(pieces(tokens.clone())) {
+        Some((operands, operators)) => {
+            let mut operands: VecDeque<Node> = operands.into_iter().map(operand).collect();
+            let mut operators = VecDeque::from(operators);
+            climb(&mut operands, &mut operators, 0)
+        }
+        None => Node::Leaf { tokens, column: 0 },
+    }
+}
+
+fn operand(operand: Operand) -> Node {
+    let mut node = match &operand.tokens[..] {
+        [TokenTree::Group(group)]
+            if group.delimiter() == Delimiter::Parenthesis
+                && !group.stream().is_empty()
+                && !has_top_level_comma(&group.stream().into_iter().collect::<Vec<_>>()) =>
+        {
+            Node::Paren {
+                inner: Box::new(parse(group.stream().into_iter().collect())),
+            }
+        }
+        _ => Node::Leaf {
+            tokens: operand.tokens,
+            column: 0,
+        },
+    };
+    for _ in 0..operand.nots {
+        node = Node::Not {
+            operand: Box::new(node),
+            column: 0,
+        };
+    }
+    node
+}
+
+/// Precedence climbing over alternating operands and binary operators.
+fn climb(operands: &mut VecDeque<Node>, operators: &mut VecDeque<String>, min: u8) -> Node {
+    let mut lhs = operands.pop_front().expect("an operand");
+    while let Some(prec) = operators.front().and_then(|op| precedence(op)) {
+        if prec < min {
+            break;
+        }
+        let operator = operators.pop_front().expect("an operator");
+        let rhs = climb(operands, operators, prec + 1);
+        lhs = Node::Binary {
+            operator,
+            lhs: Box::new(lhs),
+            rhs: Box::new(rhs),
+            column: 0,
+        };
+    }
+    lhs
+}
+
+/// Splits `pieces` into operands and the binary operators between them,
+/// or `None` if they contain something that isn't taken apart, like a
+/// range or an assignment.
+fn scan(mut pieces: VecDeque<Piece>) -> Option<(Vec<Operand>, Vec<String>)> {
+    let mut operands = Vec::new();
+    let mut operators = Vec::new();
+    let mut current = Operand {
+        nots: 0,
+        tokens: Vec::new(),
+    };
+    let mut expect_operand = true;
+    while let Some(piece) = pieces.pop_front() {
+        if expect_operand {
+            match &piece {
+                Piece::Operator(op, _) if op == "!" && current.tokens.is_empty() => {
+                    current.nots += 1;
+                }
+                Piece::Operator(op, _) if op == "|" || op == "||" => {
+                    current.tokens.extend(piece.into_tokens());
+                    pieces
+                        .drain(..)
+                        .for_each(|p| current.tokens.extend(p.into_tokens()));
+                    expect_operand = false;
+                }
+                Piece::Operator(op, _) if matches!(op.as_str(), "-" | "*" | "&" | "&&") => {
+                    current.tokens.extend(piece.into_tokens());
+                }
+                Piece::Operator(op, _) if op == "::" => {
+                    current.tokens.extend(piece.into_tokens());
+                    take_generics(&mut pieces, &mut current.tokens);
+                }
+                Piece::Operator(..) => return None,
+                Piece::Tree(TokenTree::Ident(ident))
+                    if GREEDY_KEYWORDS.contains(&ident.to_string().as_str()) =>
+                {
+                    current.tokens.extend(piece.into_tokens());
+                    pieces
+                        .drain(..)
+                        .for_each(|p| current.tokens.extend(p.into_tokens()));
+                    expect_operand = false;
+                }
+                Piece::Tree(_) if piece.is_ident("mut") => {
+                    current.tokens.extend(piece.into_tokens());
+                }
+                Piece::Tree(_) => {
+                    current.tokens.extend(piece.into_tokens());
+                    expect_operand = false;
+                }
+            }
+            continue;
+        }
+        match &piece {
+            Piece::Operator(op, _) if precedence(op).is_some() => {
+                operators.push(op.clone());
+                operands.push(mem::replace(
+                    &mut current,
+                    Operand {
+                        nots: 0,
+                        tokens: Vec::new(),
+                    },
+                ));
+                expect_operand = true;
+            }
+            Piece::Operator(op, _) if op == "." => {
+                current.tokens.extend(piece.into_tokens());
+                current.tokens.extend(pieces.pop_front()?.into_tokens());
+            }
+            Piece::Operator(op, _) if op == "::" => {
+                current.tokens.extend(piece.into_tokens());
+                take_generics(&mut pieces, &mut current.tokens);
+                current.tokens.extend(pieces.pop_front()?.into_tokens());
+            }
+            Piece::Operator(op, _) if op == "?" => current.tokens.extend(piece.into_tokens()),
+            Piece::Operator(op, _)
+                if op == "!"
+                    && matches!(pieces.front(), Some(Piece::Tree(TokenTree::Group(_)))) =>
+            {
+                current.tokens.extend(piece.into_tokens());
+                current.tokens.extend(pieces.pop_front()?.into_tokens());
+            }
+            Piece::Operator(..) => return None,
+            Piece::Tree(_) if piece.is_ident("as") => {
+                current.tokens.extend(piece.into_tokens());
+                take_type(&mut pieces, &mut current.tokens);
+            }
+            Piece::Tree(_) => current.tokens.extend(piece.into_tokens()),
+        }
+    }
+    if expect_operand {
+        return None;
+    }
+    operands.push(current);
+    Some((operands, operators))
+}
+
+/// Moves the generic arguments `<...>` at the front of `pieces`, if any,
+/// to `tokens`.
+fn take_generics(pieces: &mut VecDeque<Piece>, tokens: &mut Vec<TokenTree>) {
+    if !pieces
+        .front()
+        .is_some_and(|piece| piece.is("<") || piece.is("<<"))
+    {
+        return;
+    }
+    let mut depth = 0;
+    while let Some(piece) = pieces.pop_front() {
+        match &piece {
+            Piece::Operator(op, _) => {
+                depth += op.matches('<').count() as isize;
+                if op != "->" {
+                    depth -= op.matches('>').count() as isize;
+                }
+            }
+            Piece::Tree(_) => {}
+        }
+        tokens.extend(piece.into_tokens());
+        if depth <= 0 {
+            return;
+        }
+    }
+}
+
+/// Moves the type after an `as` at the front of `pieces` to `tokens`.
+fn take_type(pieces: &mut VecDeque<Piece>, tokens: &mut Vec<TokenTree>) {
+    while let Some(piece) = pieces.front() {
+        let part_of_type = match piece {
+            Piece::Operator(op, _) => matches!(op.as_str(), "&" | "&&" | "*" | "::"),
+            Piece::Tree(TokenTree::Ident(_)) => true,
+            Piece::Tree(TokenTree::Group(group)) => group.delimiter() != Delimiter::Brace,
+            Piece::Tree(_) => false,
+        };
+        if !part_of_type {
+            break;
+        }
+        let piece = pieces.pop_front().expect("a piece");
+        let ends_path = matches!(piece, Piece::Tree(TokenTree::Ident(_)));
+        tokens.extend(piece.into_tokens());
+        if ends_path {
+            take_generics(pieces, tokens);
+        }
+    }
+}
+
+/// Renders `node` onto `text`, recording the column of each sub-expression.
+fn layout(node: &mut Node, text: &mut String) {
+    match node {
+        Node::Leaf { tokens, column } => {
+            *column = text.chars().count();
+            text.push_str(&source(tokens.iter().cloned()));
+        }
+        Node::Not { operand, column } => {
+            *column = text.chars().count();
+            text.push('!');
+            layout(operand, text);
+        }
+        Node::Binary {
+            operator,
+            lhs,
+            rhs,
+            column,
+        } => {
+            layout(lhs, text);
+            text.push(' ');
+            *column = text.chars().count();
+            text.push_str(operator);
+            text.push(' ');
+            layout(rhs, text);
+        }
+        Node::Paren { inner } => {
+            text.push('(');
+            layout(inner, text);
+            text.push(')');
+        }
+    }
+}
+
+/// Renders `tokens` close to how they'd be written by hand, unlike
+/// [TokenStream]'s `to_string`, which puts spaces between most tokens
+/// (`v [0]`, `& x`).
+fn source(tokens: impl IntoIterator<Item = TokenTree>) -> String {
+    let mut text = String::new();
+    // Whether the previous token ends an operand, after which `-`, `*`,
+    // `&` and `!` are binary operators rather than prefixes.
+    let mut after_operand = false;
+    let mut glue_next = true;
+    // Depth of turbofish generics `::<...>`, whose brackets aren't spaced.
+    let mut generics = 0;
+    for token in tokens {
+        let glue = glue_next
+            || match &token {
+                TokenTree::Punct(punct) => {
+                    matches!(punct.as_char(), '.' | ',' | ';' | '?' | ':')
+                        || (punct.as_char() == '>' && generics > 0)
+                        || (punct.as_char() == '!' && after_operand)
+                }
+                TokenTree::Group(group) => {
+                    after_operand
+                        && matches!(
+                            group.delimiter(),
+                            Delimiter::Parenthesis | Delimiter::Bracket
+                        )
+                }
+                _ => false,
+            };
+        if !glue {
+            text.push(' ');
+        }
+        let rendered = match &token {
+            TokenTree::Group(group) => {
+                let inner = source(group.stream());
+                match group.delimiter() {
+                    Delimiter::Parenthesis => format!("({inner})"),
+                    Delimiter::Bracket => format!("[{inner}]"),
+                    Delimiter::Brace if inner.is_empty() => String::from("{}"),
+                    Delimiter::Brace => format!("{{ {inner} }}"),
+                    Delimiter::None => inner,
+                }
+            }
+            other => other.to_string(),
+        };
+        text.push_str(&rendered);
+        (after_operand, glue_next) = match &token {
+            TokenTree::Punct(punct) => {
+                let c = punct.as_char();
+                if c == '<' && text.ends_with("::<") {
+                    generics += 1;
+                } else if c == '>' && generics > 0 {
+                    generics -= 1;
+                }
+                let prefix = !after_operand && matches!(c, '-' | '*' | '&' | '!');
+                let joint = punct.spacing() == Spacing::Joint;
+                (
+                    c == '>' || c == '?',
+                    prefix || joint || c == '.' || c == '#' || text.ends_with("::<"),
+                )
+            }
+            TokenTree::Ident(ident) => {
+                let keyword = GREEDY_KEYWORDS.contains(&ident.to_string().as_str())
+                    || ident.to_string() == "mut";
+                (!keyword, false)
+            }
+            _ => (true, false),
+        };
+        if text.ends_with("::") {
+            glue_next = true;
+        }
+    }
+    text
+}
+
+/// Generates the statements that evaluate and record each sub-expression.
+///
+/// Values are recorded by reference and only rendered if the check fails,
+/// so they have to live until it's decided: the statements after a
+/// compared operand go in the `match` borrowing it, which keeps its
+/// temporaries alive. Operands of `&&` and `||` that may not be evaluated
+/// are in blocks that can't hold the rest of the check, so they record
+/// into recorders of their own.
+struct Generator {
+    statements: TokenStream,
+    /// The compared operands whose `match` the statements go in, innermost
+    /// last, each with the statements before it and its binding.
+    operands: Vec<(TokenStream, TokenTree, String)>,
+    /// The recorder values are recorded into.
+    recorder: String,
+    next: usize,
+}
+
+impl Generator {
+    fn new() -> Self {
+        Generator {
+            statements: TokenStream::new(),
+            operands: Vec::new(),
+            recorder: String::from("__recorder"),
+            next: 0,
+        }
+    }
+
+    /// Emits statements evaluating `node` into a new variable, and returns
+    /// the expression for its value. The value is recorded by reference
+    /// unless it's `moved` by what uses it, and the check passes if it's
+    /// `passes_if`, when that's known.
+    fn value(&mut self, node: &Node, moved: bool, passes_if: Option<bool>) -> TokenStream {
+        if let Node::Binary { operator, .. } = node
+            && (operator == "&&" || operator == "||")
+        {
+            return self.chain(node, operator == "&&", moved, passes_if);
+        }
+        let n = self.next;
+        self.next += 1;
+        let var = format!("__value{n}");
+        match node {
+            Node::Leaf { tokens, column } => {
+                self.emit(code(&format!("let {var} =")));
+                self.emit([parenthesized(TokenStream::from_iter(
+                    tokens.iter().cloned(),
+                ))]);
+                self.emit(code(";"));
+                self.record(*column, &var, moved);
+            }
+            Node::Not { operand, column } => {
+                let operand = self.value(operand, true, passes_if.map(|passes| !passes));
+                self.emit(code(&format!("let {var} = !")));
+                self.emit(operand);
+                self.emit(code(";"));
+                self.record(*column, &var, moved);
+            }
+            Node::Binary {
+                operator,
+                lhs,
+                rhs,
+                column,
+            } if is_comparison(operator) => {
+                // Borrows the operands, like the comparison operators do.
+                let (lhs_var, rhs_var) = (format!("__lhs{n}"), format!("__rhs{n}"));
+                self.operand(lhs, &lhs_var);
+                self.operand(rhs, &rhs_var);
+                self.emit(code(&format!(
+                    "let {var} = (*{lhs_var}) {operator} (*{rhs_var});"
+                )));
+                self.record(*column, &var, moved);
+            }
+            Node::Binary {
+                operator,
+                lhs,
+                rhs,
+                column,
+            } => {
+                let lhs = self.value(lhs, true, None);
+                let rhs = self.value(rhs, true, None);
+                self.emit(code(&format!("let {var} =")));
+                self.emit(lhs);
+                self.emit(code(operator));
+                self.emit(rhs);
+                self.emit(code(";"));
+                self.record(*column, &var, moved);
+            }
+            Node::Paren { inner } => return self.value(inner, moved, passes_if),
+        }
+        code(&var)
+    }
+
+    /// Binds `binding` to a reference to a compared operand: a leaf in the
+    /// scrutinee of a `match`, as `assert_eq!` does, so that temporaries in
+    /// it live on, and anything else after evaluating it.
+    fn operand(&mut self, node: &Node, binding: &str) {
+        match node {
+            Node::Leaf { tokens, column } => {
+                let before = mem::take(&mut self.statements);
+                let tokens = parenthesized(TokenStream::from_iter(tokens.iter().cloned()));
+                self.operands.push((before, tokens, binding.to_string()));
+                self.record(*column, binding, false);
+            }
+            Node::Paren { inner } => self.operand(inner, binding),
+            node => {
+                let value = self.value(node, false, None);
+                self.emit(code(&format!("let {binding} = &")));
+                self.emit(value);
+                self.emit(code(";"));
+            }
+        }
+    }
+
+    /// Emits statements evaluating a chain of `&&`, or of `||`, like
+    /// `a && b && c`, and returns the expression for its value, which is
+    /// settled by any operand that short-circuits and by the last one.
+    fn chain(
+        &mut self,
+        node: &Node,
+        and: bool,
+        moved: bool,
+        passes_if: Option<bool>,
+    ) -> TokenStream {
+        // `a && b && c` is parsed as `(a && b) && c`.
+        let operator = if and { "&&" } else { "||" };
+        let (mut first, mut operands, mut columns) = (node, Vec::new(), Vec::new());
+        while let Node::Binary {
+            operator: op,
+            lhs,
+            rhs,
+            column,
+        } = first
+            && op == operator
+        {
+            operands.insert(0, &**rhs);
+            columns.insert(0, *column);
+            first = lhs;
+        }
+        let vars: Vec<String> = columns
+            .iter()
+            .map(|_| {
+                self.next += 1;
+                format!("__value{}", self.next - 1)
+            })
+            .collect();
+        for var in &vars {
+            self.emit(code(&format!("let {var};")));
+        }
+        let first = self.value(first, true, passes_if.filter(|&passes| passes != and));
+        self.link(and, first, &operands, &vars, passes_if);
+        for (var, column) in vars.iter().zip(columns) {
+            self.record(column, var, moved);
+        }
+        code(&vars[vars.len() - 1])
+    }
+
+    /// Emits an `if` evaluating the `operands` left in a chain unless
+    /// `previous` short-circuits it, assigning the value of the chain up to
+    /// each operand to its var in `vars`.
+    fn link(
+        &mut self,
+        and: bool,
+        previous: TokenStream,
+        operands: &[&Node],
+        vars: &[String],
+        passes_if: Option<bool>,
+    ) {
+        if operands.is_empty() {
+            return;
+        }
+        let short_circuit: String = vars
+            .iter()
+            .map(|var| format!("{var} = {};", !and))
+            .collect();
+        let evaluated = self.side(and, operands, vars, passes_if);
+        self.emit(code(if and { "if" } else { "if !" }));
+        self.emit(previous);
+        self.emit([evaluated]);
+        self.emit(code("else"));
+        self.emit([braced(code(&short_circuit))]);
+    }
+
+    /// Returns a block evaluating the first of the `operands` left in a
+    /// chain, and the rest of the chain, into a recorder of its own, as
+    /// they may not be evaluated. Its values are rendered into the
+    /// enclosing recorder at the end of the block, unless the value of the
+    /// chain settles that the check passes.
+    fn side(
+        &mut self,
+        and: bool,
+        operands: &[&Node],
+        vars: &[String],
+        passes_if: Option<bool>,
+    ) -> TokenTree {
+        let recorder = format!("__recorder{}", self.next);
+        self.next += 1;
+        let outer = (
+            mem::take(&mut self.statements),
+            mem::take(&mut self.operands),
+            mem::replace(&mut self.recorder, recorder),
+        );
+        self.emit(code(&format!(
+            "let mut {} = ::assert4rs::__private::Recorder::default();",
+            self.recorder
+        )));
+        let settles = if operands.len() == 1 {
+            passes_if
+        } else {
+            passes_if.filter(|&passes| passes != and)
+        };
+        let value = self.value(operands[0], true, settles);
+        self.emit(code(&format!("{} =", vars[0])));
+        self.emit(value.clone());
+        self.emit(code(";"));
+        self.link(and, value, &operands[1..], &vars[1..], passes_if);
+        let adopt = code(&format!("{}.adopt({});", outer.2, self.recorder));
+        let chain = &vars[vars.len() - 1];
+        let tail = match passes_if {
+            Some(passes) => {
+                let mut unsettled = code(&format!("if {}{chain}", if passes { "!" } else { "" }));
+                unsettled.extend([braced(adopt)]);
+                unsettled
+            }
+            None => adopt,
+        };
+        let side = self.finish(tail);
+        (self.statements, self.operands, self.recorder) = outer;
+        braced(side)
+    }
+
+    /// Returns the statements so far followed by `tail`, inside the
+    /// `match` of each compared operand.
+    fn finish(&mut self, tail: TokenStream) -> TokenStream {
+        let mut body = mem::take(&mut self.statements);
+        body.extend(tail);
+        while let Some((mut before, operand, binding)) = self.operands.pop() {
+            before.extend(code("match &"));
+            before.extend([operand]);
+            let mut arm = code(&format!("{binding} =>"));
+            arm.extend([braced(body)]);
+            before.extend([braced(arm)]);
+            body = before;
+        }
+        body
+    }
+
+    fn emit(&mut self, tokens: impl IntoIterator<Item = TokenTree>) {
+        self.statements.extend(tokens);
+    }
+
+    /// Emits recording the value of `var` under `column`: by reference,
+    /// unless it's about to be `moved` and isn't [Copy].
+    fn record(&mut self, column: usize, var: &str, moved: bool) {
+        let recorded = if moved {
+            format!("(&&&::assert4rs::__private::Moved(&{var})).recorded()")
+        } else {
+            format!("(&::assert4rs::__private::Wrap(&{var})).recorded()")
+        };
+        self.emit(code(&format!(
+            "{}.record({column}, {recorded});",
+            self.recorder
+        )));
+    }
+}
+
+fn parenthesized(tokens: TokenStream) -> TokenTree {
+    TokenTree::Group(Group::new(Delimiter::Parenthesis, tokens))
+}
+
+fn braced(tokens: TokenStream) -> TokenTree {
+    TokenTree::Group(Group::new(Delimiter::Brace, tokens))
+}
+
+/// Parses generated code, with [Span::mixed_site] so its variables don't
+/// clash with those of the checked expression.
+fn code(source: &str) -> TokenStream {
+    let tokens: TokenStream = source.parse().expect("valid generated code");
+    respan(tokens)
+}
+
+fn respan(tokens: TokenStream) -> TokenStream {
+    tokens
+        .into_iter()
+        .map(|mut token| {
+            if let TokenTree::Group(group) = &token {
+                token = TokenTree::Group(Group::new(group.delimiter(), respan(group.stream())));
+            }
+            token.set_span(Span::mixed_site());
+            token
+        })
+        .collect()
+}
+
+fn compile_error(message: &str) -> TokenStream {
+    let mut error = code("::core::compile_error!");
+    error.extend([parenthesized(
+        TokenTree::Literal(Literal::string(message)).into(),
+    )]);
+    error
+}
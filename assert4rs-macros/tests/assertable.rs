@@ -0,0 +1,117 @@
+use assert4rs::{Assert, Assertable};
+use std::fmt::Debug;
+
+#[derive(Debug, Assertable)]
+struct Address {
+    city: String,
+    zip_code: u32,
+}
+
+#[derive(Debug, Assertable)]
+struct User {
+    pub name: String,
+    address: Address,
+    roles: Vec<&'static str>,
+}
+
+#[derive(Debug, Assertable)]
+struct Page<'a, T: Debug, const N: usize = 2>
+where
+    T: Clone,
+{
+    title: &'a str,
+    items: [T; N],
+}
+
+mod account {
+    use assert4rs::{Assert, Assertable};
+
+    #[derive(Debug, PartialEq)]
+    struct Secret(&'static str);
+
+    #[derive(Debug, Assertable)]
+    pub struct Account {
+        pub owner: String,
+        secret: Secret,
+    }
+
+    pub fn account() -> Account {
+        Account {
+            owner: String::from("Ann"),
+            secret: Secret("hunter2"),
+        }
+    }
+
+    #[test]
+    fn private_fields_have_private_accessors() {
+        Assert::that(account()).secret().is(Secret("hunter2"));
+        Assert::that(&account()).secret().is(&Secret("hunter2"));
+    }
+}
+
+fn user() -> User {
+    User {
+        name: String::from("Ann"),
+        address: Address {
+            city: String::from("Utrecht"),
+            zip_code: 3511,
+        },
+        roles: vec!["admin"],
+    }
+}
+
+#[test]
+fn accessors_navigate_to_fields() {
+    Assert::that(user()).name().is("Ann");
+    Assert::that(user()).address().zip_code().is(3511);
+    Assert::that(user()).roles().contains(&"admin");
+}
+
+#[test]
+fn accessors_borrow_from_referenced_structs() {
+    let user = user();
+    Assert::that(&user)
+        .address()
+        .city()
+        .is(&String::from("Utrecht"));
+    Assert::that(&user).roles().has_length(1);
+    Assert::that(user.name).is("Ann");
+}
+
+#[test]
+fn failure_names_the_field_path() {
+    let error = Assert::check(user(), |a| a.address().city().is("Amsterdam"))
+        .err()
+        .unwrap();
+    assert_eq!(error.label(), Some("user.address.city"));
+}
+
+#[test]
+fn field_extends_an_existing_label() {
+    let error = Assert::check(user(), |a| a.named("admin").address().zip_code().is(1))
+        .err()
+        .unwrap();
+    assert_eq!(error.label(), Some("admin.address.zip_code"));
+}
+
+#[test]
+fn generic_structs_are_supported() {
+    let page = Page {
+        title: "home",
+        items: [1, 2],
+    };
+    Assert::that(&page).title().is(&"home");
+    let error = Assert::check(page, |a| a.items().is([1, 3])).err().unwrap();
+    assert_eq!(error.label(), Some("page.items"));
+}
+
+#[test]
+fn public_fields_of_public_structs_have_public_accessors() {
+    use account::AccountFields;
+    Assert::that(account::account()).owner().is("Ann");
+}
+
+#[test]
+fn not_carries_through_accessors() {
+    Assert::that(user()).not().name().is("Bob");
+}
@@ -0,0 +1,167 @@
+use assert4rs::{Assert, check};
+use std::panic::{self, UnwindSafe};
+
+fn failure(f: impl FnOnce() + UnwindSafe) -> String {
+    let panic = panic::catch_unwind(f).unwrap_err();
+    panic.downcast_ref::<String>().unwrap().clone()
+}
+
+#[test]
+fn passing_check_does_not_panic() {
+    let a = [1, 2, 3];
+    check!(a.len() == 3 && a.contains(&2));
+}
+
+#[test]
+fn failure_shows_sub_expression_values() {
+    let a = [1, 2, 3];
+    let b = [1];
+    let message = failure(|| check!(a.len() == b.len() + 1));
+    assert!(
+        message.contains(
+            "  a.len() == b.len() + 1\
+             \n  |       |  |       | |\
+             \n  3       |  1       2 1\
+             \n          false"
+        ),
+        "{message}"
+    );
+}
+
+#[test]
+fn negation_and_parentheses_are_taken_apart() {
+    let x = 5;
+    let message = failure(|| check!(!(x > 3)));
+    assert!(
+        message.contains("  !(x > 3)\n  | | | |\n  | 5 | 3\n  |   true\n  false"),
+        "{message}"
+    );
+}
+
+#[test]
+fn short_circuited_operands_are_not_shown() {
+    let v: Vec<i32> = Vec::new();
+    let message = failure(|| check!(!v.is_empty() && v[0] == 1));
+    assert!(
+        message.contains(
+            "  !v.is_empty() && v[0] == 1\
+             \n  ||            |\
+             \n  |true         false\
+             \n  false"
+        ),
+        "{message}"
+    );
+}
+
+#[test]
+fn values_without_debug_are_placeholders() {
+    struct Opaque(u8);
+    impl PartialEq for Opaque {
+        fn eq(&self, other: &Self) -> bool {
+            self.0 == other.0
+        }
+    }
+    let message = failure(|| check!(Opaque(1) == Opaque(2)));
+    assert!(message.contains("<?>"), "{message}");
+}
+
+#[test]
+fn compared_values_are_borrowed_not_moved() {
+    let name = String::from("Alice");
+    check!(name == "Alice");
+    check!(&name[..2] != "Bo" && -(name.len() as i64) < 0);
+    check!(name.len() as u64 > 2u64);
+    check!(Vec::<u8>::new().is_empty() || name.is_empty());
+    assert_eq!(name, "Alice");
+}
+
+#[test]
+fn temporaries_live_through_the_comparison() {
+    fn name() -> String {
+        String::from("a")
+    }
+    check!(name().as_str() == "a");
+    check!(name().as_str() != "b" && name().as_bytes()[0] == b'a');
+    let message = failure(|| check!(name().as_str() == "b"));
+    assert!(
+        message.contains("  name().as_str() == \"b\"\n  |               |  |\n  \"a\"             |  \"b\"\n                  false"),
+        "{message}"
+    );
+}
+
+#[test]
+fn unsupported_expressions_are_checked_whole() {
+    let message = failure(|| check!((0..3).contains(&5)));
+    assert!(
+        message.contains("  (0..3).contains(&5)\n  |\n  false"),
+        "{message}"
+    );
+}
+
+#[test]
+fn failures_are_collected_like_assertions() {
+    let report = failure(|| {
+        Assert::soft(|s| {
+            check!(1 + 1 == 3);
+            s.that(2).is(2);
+            check!("a" != "a");
+        })
+    });
+    assert!(report.starts_with("2 soft assertions failed:"), "{report}");
+    assert!(report.contains("`(1 + 1 == 3)`"), "{report}");
+    assert!(report.contains("`(\"a\" != \"a\")`"), "{report}");
+    let error = Assert::check((), |a| {
+        check!(1 > 2);
+        a
+    })
+    .err()
+    .unwrap();
+    assert_eq!(error.header(), "Assertion failed: `(1 > 2)`");
+    let message = failure(|| check!(1 > 2));
+    assert!(
+        message.starts_with("Assertion failed: `(1 > 2)`"),
+        "{message}"
+    );
+}
+
+#[test]
+fn values_are_only_rendered_when_the_check_fails() {
+    use std::fmt;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static RENDERED: AtomicUsize = AtomicUsize::new(0);
+
+    #[derive(Clone, Copy, PartialEq, PartialOrd)]
+    struct Counted(u32);
+    impl fmt::Debug for Counted {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            RENDERED.fetch_add(1, Ordering::Relaxed);
+            write!(f, "Counted({})", self.0)
+        }
+    }
+    impl std::ops::Add for Counted {
+        type Output = Counted;
+        fn add(self, other: Counted) -> Counted {
+            Counted(self.0 + other.0)
+        }
+    }
+    let (one, two) = (Counted(1), Counted(2));
+    let list = [one, two];
+
+    check!(one < two && list[1] == two && one + one == two);
+    check!(one == two || !(list[0] != one));
+    check!((one > two || list.len() == 2) == (two >= one));
+    assert_eq!(RENDERED.load(Ordering::Relaxed), 0);
+
+    let message = failure(|| check!(one == one && two + one == two));
+    assert!(
+        message.contains(
+            "  one == one && two + one == two\
+             \n  |   |  |   |  |   | |   |  |\
+             \n  |   |  |   |  |   | |   |  Counted(2)\
+             \n  |   |  |   |  |   | |   false"
+        ),
+        "{message}"
+    );
+    assert!(message.contains("Counted(3)"), "{message}");
+}
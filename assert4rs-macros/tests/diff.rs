@@ -0,0 +1,117 @@
+use assert4rs::{Assert, Change, Diff};
+use std::collections::HashMap;
+use std::fmt::Debug;
+
+#[derive(Debug, PartialEq, Diff)]
+struct Line {
+    sku: String,
+    qty: u32,
+}
+
+#[derive(Debug, PartialEq, Diff)]
+struct Order {
+    id: u32,
+    lines: Vec<Line>,
+    notes: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Diff)]
+struct Customer {
+    orders: HashMap<u32, Order>,
+}
+
+#[derive(Debug, PartialEq, Diff)]
+enum Event {
+    Created,
+    Renamed(String, String),
+    Moved { from: (), to: u32 },
+}
+
+#[derive(Debug, PartialEq, Diff)]
+struct Wrapper<'a, T>(&'a T)
+where
+    T: Debug;
+
+#[derive(Debug, PartialEq, Diff)]
+struct Empty;
+
+fn described<T: Diff>(actual: &T, expected: &T) -> Vec<String> {
+    actual
+        .diff(expected)
+        .iter()
+        .map(Change::to_string)
+        .collect()
+}
+
+fn order(qty: u32) -> Order {
+    Order {
+        id: 1,
+        lines: vec![
+            Line {
+                sku: String::from("apple"),
+                qty: 1,
+            },
+            Line {
+                sku: String::from("pear"),
+                qty,
+            },
+        ],
+        notes: None,
+    }
+}
+
+#[test]
+fn struct_changes_are_named_by_field_path() {
+    let customer = |qty| Customer {
+        orders: HashMap::from([(7, order(qty))]),
+    };
+    assert_eq!(
+        described(&customer(2), &customer(3)),
+        [".orders[7].lines[1].qty: 2 != 3"]
+    );
+    assert!(described(&customer(2), &customer(2)).is_empty());
+}
+
+#[test]
+fn enum_variants_compare_their_fields() {
+    assert_eq!(
+        described(
+            &Event::Renamed(String::from("a"), String::from("b")),
+            &Event::Renamed(String::from("a"), String::from("c"))
+        ),
+        [".1: \"b\" != \"c\""]
+    );
+    assert_eq!(
+        described(
+            &Event::Moved { from: (), to: 1 },
+            &Event::Moved { from: (), to: 2 }
+        ),
+        [".to: 1 != 2"]
+    );
+    assert_eq!(
+        described(&Event::Created, &Event::Moved { from: (), to: 2 }),
+        ["Created != Moved { from: (), to: 2 }"]
+    );
+    assert!(described(&Event::Created, &Event::Created).is_empty());
+}
+
+#[test]
+fn generic_and_unit_structs_are_supported() {
+    assert_eq!(described(&Wrapper(&1), &Wrapper(&2)), [".0: 1 != 2"]);
+    assert!(described(&Empty, &Empty).is_empty());
+}
+
+#[test]
+fn is_eq_to_lists_changes() {
+    let error = Assert::check(order(2), |a| a.named("order").is_eq_to(order(5)))
+        .err()
+        .unwrap();
+    assert_eq!(
+        error.header(),
+        "Assertion failed for `order`: `(actual.is_eq_to(expected))`"
+    );
+    assert_eq!(
+        error.diff(),
+        Some("  Differences:\n    .lines[1].qty: 2 != 5")
+    );
+}
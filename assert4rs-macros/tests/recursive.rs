@@ -0,0 +1,129 @@
+use assert4rs::{Assert, Diff};
+
+#[derive(Debug, PartialEq, Diff)]
+struct Tag {
+    id: u32,
+    name: String,
+    created_at: u64,
+}
+
+#[derive(Debug, PartialEq, Diff)]
+struct Response {
+    id: u32,
+    created_at: u64,
+    score: f64,
+    tags: Vec<Tag>,
+}
+
+#[derive(Debug, PartialEq, Diff)]
+struct Address {
+    city: String,
+    created_at: u64,
+}
+
+#[derive(Debug, PartialEq, Diff)]
+struct Profile {
+    address: Option<Address>,
+    tags: Option<Vec<Tag>>,
+}
+
+fn tag(id: u32, name: &str, created_at: u64) -> Tag {
+    Tag {
+        id,
+        name: name.to_string(),
+        created_at,
+    }
+}
+
+fn approx(a: &f64, b: &f64) -> bool {
+    (a - b).abs() < 1e-9
+}
+
+#[test]
+fn derived_types_are_compared_as_set_up() {
+    let actual = Response {
+        id: 1,
+        created_at: 100,
+        score: 0.1 + 0.2,
+        tags: vec![tag(10, "b", 101), tag(11, "a", 102)],
+    };
+    let expected = Response {
+        id: 2,
+        created_at: 200,
+        score: 0.3,
+        tags: vec![tag(10, "a", 201), tag(11, "b", 202)],
+    };
+    Assert::that(actual)
+        .comparing_recursively()
+        .ignoring_fields(["id", "*.created_at", "tags.id"])
+        .ignoring_collection_order_in(["tags"])
+        .with_comparator_for::<f64>(approx)
+        .is(expected);
+}
+
+#[test]
+fn failure_lists_field_paths_left_in() {
+    let actual = Response {
+        id: 1,
+        created_at: 100,
+        score: 1.0,
+        tags: vec![tag(10, "a", 101)],
+    };
+    let expected = Response {
+        id: 2,
+        created_at: 200,
+        score: 2.0,
+        tags: vec![tag(11, "a", 202)],
+    };
+    let error = Assert::check(actual, |a| {
+        a.comparing_recursively()
+            .ignoring_fields(["*.created_at"])
+            .is(expected)
+    })
+    .err()
+    .unwrap();
+    assert_eq!(
+        error.diff(),
+        Some("  Differences:\n    .id: 1 != 2\n    .score: 1.0 != 2.0\n    .tags[0].id: 10 != 11")
+    );
+    assert_eq!(
+        error.details().last().map(|detail| detail.value()),
+        Some("*.created_at")
+    );
+}
+
+#[test]
+fn comparator_for_derived_type_replaces_its_fields() {
+    let by_name = |a: &Tag, b: &Tag| a.name == b.name;
+    Assert::that(vec![tag(1, "a", 1), tag(2, "b", 2)])
+        .comparing_recursively()
+        .with_comparator_for(by_name)
+        .is(vec![tag(3, "a", 3), tag(4, "b", 4)]);
+}
+
+#[test]
+fn field_paths_see_through_options() {
+    let profile = |created_at, tags| Profile {
+        address: Some(Address {
+            city: String::from("Lyon"),
+            created_at,
+        }),
+        tags: Some(tags),
+    };
+    Assert::that(profile(1, vec![tag(1, "a", 1), tag(2, "b", 2)]))
+        .comparing_recursively()
+        .ignoring_fields(["address.created_at"])
+        .ignoring_collection_order_in(["tags"])
+        .is(profile(2, vec![tag(2, "b", 2), tag(1, "a", 1)]));
+    let error = Assert::check(profile(1, vec![]), |a| {
+        a.comparing_recursively()
+            .ignoring_fields(["tags"])
+            .is(profile(2, vec![tag(1, "a", 1)]))
+    })
+    .err()
+    .unwrap();
+    assert_eq!(
+        error.diff(),
+        Some("  Differences:\n    .address.0.created_at: 1 != 2")
+    );
+}
@@ -0,0 +1,242 @@
+//! Approximate equality: [Diff] comparisons that let floats anywhere in
+//! the values compared be a little apart.
+
+use std::fmt::{self, Display};
+
+use crate::Assert;
+use crate::structural_diff::{Comparison, Diff, Rules};
+
+/// How far apart two floats may be and still count as equal, for
+/// [`Assert::is_approx_eq`]: at most an absolute difference, at most a
+/// difference relative to the larger of the two, or either.
+///
+/// ```
+/// # use assert4rs::Tolerance;
+/// let tolerance = Tolerance::abs(1e-9).or_rel(1e-6);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tolerance {
+    absolute: f64,
+    relative: f64,
+}
+
+impl Tolerance {
+    /// Floats may differ by at most `absolute`.
+    pub fn abs(absolute: f64) -> Self {
+        Tolerance {
+            absolute,
+            relative: 0.0,
+        }
+    }
+
+    /// Floats may differ by at most `relative` times the larger of the
+    /// two, e.g. `1e-6` for a millionth.
+    pub fn rel(relative: f64) -> Self {
+        Tolerance {
+            absolute: 0.0,
+            relative,
+        }
+    }
+
+    /// Floats may also differ by at most `relative` times the larger of
+    /// the two.
+    pub fn or_rel(self, relative: f64) -> Self {
+        Tolerance { relative, ..self }
+    }
+
+    /// Floats may also differ by at most `absolute`.
+    pub fn or_abs(self, absolute: f64) -> Self {
+        Tolerance { absolute, ..self }
+    }
+
+    /// By how much `actual` is further from `expected` than allowed, or
+    /// `None` if it isn't. NaN is never within tolerance.
+    pub(crate) fn excess(&self, actual: f64, expected: f64) -> Option<String> {
+        if actual == expected {
+            return None;
+        }
+        let difference = (actual - expected).abs();
+        let relative = difference / actual.abs().max(expected.abs());
+        if difference <= self.absolute || relative <= self.relative {
+            return None;
+        }
+        Some(format!("off by {difference:.2e}, {relative:.2e} relative"))
+    }
+}
+
+/// Renders e.g. `1e-9`, `1e-6 relative` or `1e-9 or 1e-6 relative`.
+impl Display for Tolerance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.absolute, self.relative) {
+            (absolute, 0.0) => write!(f, "{absolute:e}"),
+            (0.0, relative) => write!(f, "{relative:e} relative"),
+            (absolute, relative) => write!(f, "{absolute:e} or {relative:e} relative"),
+        }
+    }
+}
+
+impl<T: Diff> Assert<T> {
+    /// Assert that `self` equals `expected` up to float noise: compares
+    /// them part by part with [Diff], like [`Assert::is_eq_to`], letting
+    /// floats anywhere inside differ within `tolerance` and comparing
+    /// everything else exactly. Failures list the float paths out of
+    /// tolerance and by how much.
+    ///
+    /// ```
+    /// # use assert4rs::Assert;
+    /// # use assert4rs::Tolerance;
+    /// Assert::that(vec![0.1 + 0.2, 1.0]).is_approx_eq(vec![0.3, 1.0], Tolerance::abs(1e-9));
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use assert4rs::Assert;
+    /// # use assert4rs::Tolerance;
+    /// // Assertion failed: `(actual.is_approx_eq(expected))`
+    /// //   Actual:   `[0.30000000000000004, 1.001]`
+    /// //   Expected: `[0.3, 1.0]`
+    /// //   Tolerance: `1e-9 or 1e-6 relative`
+    /// //   Differences:
+    /// //     [1]: 1.001 != 1.0 (off by 1.00e-3, 9.99e-4 relative)
+    /// Assert::that(vec![0.1 + 0.2, 1.001])
+    ///     .is_approx_eq(vec![0.3, 1.0], Tolerance::abs(1e-9).or_rel(1e-6));
+    /// ```
+    #[track_caller]
+    pub fn is_approx_eq(mut self, expected: T, tolerance: Tolerance) -> Self {
+        let negated = self.take_negation();
+        let comparison = Comparison::new(Rules {
+            tolerance: Some(tolerance),
+            ..Rules::default()
+        });
+        let changes = self.actual.diff_with(&expected, &comparison);
+        if changes.is_empty() != negated {
+            return self;
+        }
+        let error = self
+            .changes_error(
+                "actual.is_approx_eq(expected)",
+                &expected,
+                &changes,
+                negated,
+            )
+            .detail("Tolerance", tolerance.to_string());
+        self.fail(error);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Change;
+    use std::collections::HashMap;
+
+    #[derive(Debug, PartialEq)]
+    struct Point {
+        label: String,
+        x: f64,
+        y: f32,
+    }
+
+    impl Diff for Point {
+        fn diff_with(&self, other: &Self, comparison: &Comparison) -> Vec<Change> {
+            let mut changes = comparison.diff_at(".label", &self.label, &other.label);
+            changes.extend(comparison.diff_at(".x", &self.x, &other.x));
+            changes.extend(comparison.diff_at(".y", &self.y, &other.y));
+            changes
+        }
+    }
+
+    fn point(label: &str, x: f64, y: f32) -> Point {
+        Point {
+            label: label.to_string(),
+            x,
+            y,
+        }
+    }
+
+    #[test]
+    fn tolerance_allows_absolute_or_relative_difference() {
+        let tolerance = Tolerance::abs(1e-9).or_rel(1e-6);
+        assert_eq!(tolerance.excess(1.0, 1.0 + 1e-10), None);
+        assert_eq!(tolerance.excess(1e6, 1e6 + 0.5), None);
+        assert!(tolerance.excess(1.0, 1.0 + 1e-5).is_some());
+        assert!(Tolerance::rel(1e-6).excess(0.0, 1e-12).is_some());
+    }
+
+    #[test]
+    fn tolerance_never_allows_nan() {
+        let tolerance = Tolerance::abs(f64::INFINITY);
+        assert!(tolerance.excess(f64::NAN, f64::NAN).is_some());
+        assert!(tolerance.excess(f64::NAN, 1.0).is_some());
+        assert_eq!(tolerance.excess(f64::INFINITY, f64::INFINITY), None);
+    }
+
+    #[test]
+    fn tolerance_display() {
+        assert_eq!(Tolerance::abs(1e-9).to_string(), "1e-9");
+        assert_eq!(Tolerance::rel(1e-6).to_string(), "1e-6 relative");
+        assert_eq!(
+            Tolerance::abs(1e-9).or_rel(1e-6).to_string(),
+            "1e-9 or 1e-6 relative"
+        );
+    }
+
+    #[test]
+    fn is_approx_eq_tolerates_float_noise_in_nested_values() {
+        let actual = HashMap::from([(1, vec![point("a", 0.1 + 0.2, 0.1 + 0.2)])]);
+        let expected = HashMap::from([(1, vec![point("a", 0.3, 0.3)])]);
+        Assert::that(actual).is_approx_eq(expected, Tolerance::abs(1e-6));
+    }
+
+    #[test]
+    fn is_approx_eq_compares_everything_else_exactly() {
+        let error = Assert::check(vec![point("a", 1.0, 1.0)], |a| {
+            a.is_approx_eq(vec![point("b", 1.0, 1.0)], Tolerance::abs(1.0))
+        })
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.diff(),
+            Some("  Differences:\n    [0].label: \"a\" != \"b\"")
+        );
+    }
+
+    #[test]
+    fn is_approx_eq_reports_paths_out_of_tolerance() {
+        let error = Assert::check(vec![point("a", 1.5, 2.0), point("b", 3.0, 4.0)], |a| {
+            a.is_approx_eq(
+                vec![point("a", 1.0, 2.0), point("b", 3.0, 4.25)],
+                Tolerance::abs(0.1),
+            )
+        })
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.header(),
+            "Assertion failed: `(actual.is_approx_eq(expected))`"
+        );
+        assert_eq!(error.details().last().map(|d| d.value()), Some("1e-1"));
+        assert_eq!(
+            error.diff(),
+            Some(
+                "  Differences:\n    \
+                 [0].x: 1.5 != 1.0 (off by 5.00e-1, 3.33e-1 relative)\n    \
+                 [1].y: 4.0 != 4.25 (off by 2.50e-1, 5.88e-2 relative)"
+            )
+        );
+    }
+
+    #[test]
+    fn not_is_approx_eq_fails_within_tolerance() {
+        let error = Assert::check(1.0, |a| {
+            a.not().is_approx_eq(1.0 + 1e-12, Tolerance::abs(1e-9))
+        })
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.header(),
+            "Assertion failed: `!(actual.is_approx_eq(expected))`"
+        );
+        assert_eq!(error.expected(), Some("not 1.000000000001"));
+    }
+}
@@ -0,0 +1,83 @@
+//! Collects failures instead of panicking, for
+//! [`Assert::soft`](crate::Assert::soft) and
+//! [`Assert::check`](crate::Assert::check).
+
+use std::any::Any;
+use std::cell::RefCell;
+use std::panic;
+use std::sync::{Arc, Mutex};
+
+use crate::error::AssertionError;
+
+/// Shared sink for the failures of one `Assert::soft` or `Assert::check`
+/// scope. Every `Assert` of that scope (including those
+/// derived from it via `map`, `unwrap`, `get`, …) holds a handle to it.
+#[derive(Default)]
+pub(crate) struct Collector {
+    failures: Mutex<Vec<AssertionError>>,
+    /// Whether the scope ends at the first failure, as for `Assert::check`.
+    stop_at_first: bool,
+}
+
+impl Collector {
+    pub(crate) fn first_failure() -> Self {
+        Collector {
+            stop_at_first: true,
+            ..Collector::default()
+        }
+    }
+
+    /// Records `error`, ending the scope if it only wants the first one.
+    pub(crate) fn record(&self, error: AssertionError) {
+        self.failures.lock().unwrap().push(error);
+        if self.stop_at_first {
+            abort();
+        }
+    }
+
+    pub(crate) fn take(&self) -> Vec<AssertionError> {
+        std::mem::take(&mut self.failures.lock().unwrap())
+    }
+}
+
+thread_local! {
+    /// The collectors of the scopes running on this thread, innermost
+    /// last, for failures that have no `Assert` to hand them one, like
+    /// those of `check!`.
+    static SCOPES: RefCell<Vec<Arc<Collector>>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Runs `f` as the innermost scope on this thread, collecting into
+/// `collector`.
+pub(crate) fn in_scope<R>(collector: &Arc<Collector>, f: impl FnOnce() -> R) -> R {
+    /// Leaves the scope however `f` ends, unwinding included.
+    struct Leave;
+    impl Drop for Leave {
+        fn drop(&mut self) {
+            SCOPES.with_borrow_mut(Vec::pop);
+        }
+    }
+    SCOPES.with_borrow_mut(|scopes| scopes.push(collector.clone()));
+    let _leave = Leave;
+    f()
+}
+
+/// The collector of the innermost scope running on this thread, if any.
+pub(crate) fn current() -> Option<Arc<Collector>> {
+    SCOPES.with_borrow(|scopes| scopes.last().cloned())
+}
+
+/// Unwind payload used to end a scope early, see `Assert::abort`.
+struct Abort;
+
+/// Ends the current scope without invoking the panic hook, so the
+/// failure that caused it is only reported once, by the scope itself.
+pub(crate) fn abort() -> ! {
+    panic::resume_unwind(Box::new(Abort))
+}
+
+/// Whether an unwind `payload` comes from [abort] rather than from a
+/// panic in user code.
+pub(crate) fn is_abort(payload: &(dyn Any + Send)) -> bool {
+    payload.is::<Abort>()
+}
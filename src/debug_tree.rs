@@ -0,0 +1,637 @@
+//! Structural diff of arbitrary values through their `{:?}` renderings:
+//! [parse] turns a rendering back into a tree of structs, tuples, lists,
+//! maps and sets, and [differences] names the path to every part in which
+//! two trees differ, e.g. `.orders[3].lines[0].qty: 2 != 3`.
+//!
+//! Unlike `structural_diff.rs`, this needs nothing beyond [Debug](std::fmt::Debug),
+//! at the price of comparing renderings rather than values.
+
+use crate::diff::{Edit, edit_script};
+use crate::structural_diff::Change;
+
+/// How deeply values may nest before [parse] gives up on a rendering,
+/// so that parsing and diffing it can't overflow the stack.
+const MAX_DEPTH: usize = 128;
+
+/// Above this many bytes of renderings, [describe_differences] leaves
+/// them to the plain text diff, which stays fast on large inputs.
+const MAX_DESCRIBED_LEN: usize = 100_000;
+
+/// A parsed `{:?}` rendering.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Value {
+    /// A struct or struct-like enum variant, `Name { field: value }`.
+    Struct(String, Vec<(String, Value)>),
+    /// A tuple struct or tuple-like enum variant such as `Some(1)`, or a
+    /// plain tuple when the name is empty.
+    Tuple(String, Vec<Value>),
+    List(Vec<Value>),
+    Map(Vec<(Value, Value)>),
+    Set(Vec<Value>),
+    /// Anything else: a number, string, char, bool, unit variant, ...
+    Scalar(String),
+}
+
+impl Value {
+    /// Renders the value the way `{:?}` did.
+    pub(crate) fn render(&self) -> String {
+        let join = |items: &[Value]| {
+            items
+                .iter()
+                .map(Value::render)
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        match self {
+            Value::Struct(name, fields) if fields.is_empty() => name.clone(),
+            Value::Struct(name, fields) => {
+                let fields: Vec<String> = fields
+                    .iter()
+                    .map(|(field, value)| match field.as_str() {
+                        ".." => field.clone(),
+                        _ => format!("{field}: {}", value.render()),
+                    })
+                    .collect();
+                format!("{name} {{ {} }}", fields.join(", "))
+            }
+            Value::Tuple(name, items) if name.is_empty() && items.len() == 1 => {
+                format!("({},)", items[0].render())
+            }
+            Value::Tuple(name, items) => format!("{name}({})", join(items)),
+            Value::List(items) => format!("[{}]", join(items)),
+            Value::Map(entries) => {
+                let entries: Vec<String> = entries
+                    .iter()
+                    .map(|(key, value)| format!("{}: {}", key.render(), value.render()))
+                    .collect();
+                format!("{{{}}}", entries.join(", "))
+            }
+            Value::Set(items) => format!("{{{}}}", join(items)),
+            Value::Scalar(text) => text.clone(),
+        }
+    }
+}
+
+/// Parses a `{:?}` or `{:#?}` rendering, or returns `None` if it isn't
+/// shaped like the output of derived and standard `Debug` impls, or
+/// nests more than [MAX_DEPTH] levels deep.
+pub(crate) fn parse(text: &str) -> Option<Value> {
+    let mut parser = Parser {
+        chars: text.chars().collect(),
+        pos: 0,
+        depth: 0,
+    };
+    let value = parser.value()?;
+    parser.skip_whitespace();
+    (parser.pos == parser.chars.len()).then_some(value)
+}
+
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+    /// How many values enclose the one being parsed.
+    depth: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.pos += 1;
+        }
+    }
+
+    /// Consumes `c` after any whitespace, if it's next.
+    fn eat(&mut self, c: char) -> bool {
+        self.skip_whitespace();
+        let found = self.peek() == Some(c);
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    fn value(&mut self) -> Option<Value> {
+        if self.depth == MAX_DEPTH {
+            return None;
+        }
+        self.depth += 1;
+        self.skip_whitespace();
+        let value = match self.peek()? {
+            '"' | '\'' => self.quoted().map(Value::Scalar),
+            '[' => {
+                self.pos += 1;
+                self.items(']').map(Value::List)
+            }
+            '(' => {
+                self.pos += 1;
+                self.items(')')
+                    .map(|items| Value::Tuple(String::new(), items))
+            }
+            '{' => {
+                self.pos += 1;
+                self.map_or_set()
+            }
+            c if c.is_alphabetic() || c == '_' => self.named(),
+            _ => self.scalar(),
+        };
+        self.depth -= 1;
+        value
+    }
+
+    /// A string or char literal, escapes included.
+    fn quoted(&mut self) -> Option<String> {
+        let quote = self.peek()?;
+        let start = self.pos;
+        self.pos += 1;
+        loop {
+            match self.peek()? {
+                '\\' => self.pos += 2,
+                c => {
+                    self.pos += 1;
+                    if c == quote {
+                        break;
+                    }
+                }
+            }
+        }
+        Some(self.chars.get(start..self.pos)?.iter().collect())
+    }
+
+    /// Comma-separated values up to `close`, the opening bracket already
+    /// consumed.
+    fn items(&mut self, close: char) -> Option<Vec<Value>> {
+        let mut items = Vec::new();
+        while !self.eat(close) {
+            items.push(self.value()?);
+            if !self.eat(',') && self.peek() != Some(close) {
+                return None;
+            }
+        }
+        Some(items)
+    }
+
+    fn map_or_set(&mut self) -> Option<Value> {
+        if self.eat('}') {
+            return Some(Value::Map(Vec::new()));
+        }
+        let first = self.value()?;
+        if !self.eat(':') {
+            let mut items = vec![first];
+            if self.eat(',') {
+                items.extend(self.items('}')?);
+            } else if !self.eat('}') {
+                return None;
+            }
+            return Some(Value::Set(items));
+        }
+        let mut entries = vec![(first, self.value()?)];
+        while !self.eat('}') {
+            if !self.eat(',') {
+                return None;
+            }
+            if self.eat('}') {
+                break;
+            }
+            let key = self.value()?;
+            if !self.eat(':') {
+                return None;
+            }
+            entries.push((key, self.value()?));
+        }
+        Some(Value::Map(entries))
+    }
+
+    /// A struct, tuple struct or unit value starting with its name.
+    fn named(&mut self) -> Option<Value> {
+        let name = self.path();
+        self.skip_whitespace();
+        match self.peek() {
+            Some('(') => {
+                self.pos += 1;
+                Some(Value::Tuple(name, self.items(')')?))
+            }
+            Some('{') => {
+                self.pos += 1;
+                let mut fields = Vec::new();
+                while !self.eat('}') {
+                    self.skip_whitespace();
+                    if self.chars[self.pos..].starts_with(&['.', '.']) {
+                        // `finish_non_exhaustive`
+                        self.pos += 2;
+                        fields.push((String::from(".."), Value::Scalar(String::new())));
+                        continue;
+                    }
+                    let field = self.path();
+                    if field.is_empty() || !self.eat(':') {
+                        return None;
+                    }
+                    fields.push((field, self.value()?));
+                    if !self.eat(',') && self.peek() != Some('}') {
+                        return None;
+                    }
+                }
+                Some(Value::Struct(name, fields))
+            }
+            _ => Some(Value::Scalar(name)),
+        }
+    }
+
+    /// An identifier, possibly qualified with `::`.
+    fn path(&mut self) -> String {
+        let start = self.pos;
+        while let Some(c) = self.peek() {
+            if c.is_alphanumeric() || c == '_' {
+                self.pos += 1;
+            } else if self.chars[self.pos..].starts_with(&[':', ':']) {
+                self.pos += 2;
+            } else {
+                break;
+            }
+        }
+        self.chars[start..self.pos].iter().collect()
+    }
+
+    /// Anything up to the next separator outside of brackets, like a
+    /// number or range.
+    fn scalar(&mut self) -> Option<Value> {
+        let start = self.pos;
+        let mut depth = 0usize;
+        while let Some(c) = self.peek() {
+            match c {
+                '(' | '[' | '{' => depth += 1,
+                ')' | ']' | '}' if depth == 0 => break,
+                ')' | ']' | '}' => depth -= 1,
+                ',' if depth == 0 => break,
+                ':' if depth == 0 && self.chars.get(self.pos + 1) != Some(&':') => break,
+                _ => {}
+            }
+            self.pos += 1;
+        }
+        let text: String = self.chars[start..self.pos].iter().collect();
+        let text = text.trim_end();
+        (!text.is_empty()).then(|| Value::Scalar(text.to_string()))
+    }
+}
+
+/// The parts in which `actual` and `expected` differ, empty if they're
+/// equal. Fields are matched by name, map entries by key, and set
+/// elements, like map entries, regardless of their order; lists of
+/// different lengths are matched up by their longest common subsequence.
+pub(crate) fn differences(actual: &Value, expected: &Value) -> Vec<Change> {
+    let mut found = Vec::new();
+    diff(actual, expected, "", &mut found);
+    found
+}
+
+/// Lists the paths at which the `{:?}` renderings `actual` and `expected`
+/// differ, under a `Differences:` heading. Returns `None` if either
+/// doesn't parse or together they're longer than [MAX_DESCRIBED_LEN], or
+/// if the values differ as a whole, so there's no path to point out.
+pub(crate) fn describe_differences(actual: &str, expected: &str) -> Option<String> {
+    if actual.len() + expected.len() > MAX_DESCRIBED_LEN {
+        return None;
+    }
+    let found = differences(&parse(actual)?, &parse(expected)?);
+    if found.is_empty() || found.iter().any(Change::is_whole) {
+        return None;
+    }
+    Some(crate::structural_diff::describe(&found))
+}
+
+fn diff(actual: &Value, expected: &Value, path: &str, found: &mut Vec<Change>) {
+    match (actual, expected) {
+        (Value::Struct(a_name, a_fields), Value::Struct(e_name, e_fields)) if a_name == e_name => {
+            // The `..` of `finish_non_exhaustive` stands for no field in particular.
+            let a_fields = a_fields.iter().filter(|(name, _)| name != "..");
+            let e_fields = e_fields.iter().filter(|(name, _)| name != "..");
+            for (field, a) in a_fields.clone() {
+                let at = format!("{path}.{field}");
+                match e_fields.clone().find(|(name, _)| name == field) {
+                    Some((_, e)) => diff(a, e, &at, found),
+                    None => found.push(only_actual(at, a)),
+                }
+            }
+            for (field, e) in e_fields {
+                if !a_fields.clone().any(|(name, _)| name == field) {
+                    found.push(only_expected(format!("{path}.{field}"), e));
+                }
+            }
+        }
+        (Value::Tuple(a_name, a_items), Value::Tuple(e_name, e_items))
+            if a_name == e_name && a_items.len() == e_items.len() =>
+        {
+            for (i, (a, e)) in a_items.iter().zip(e_items).enumerate() {
+                diff(a, e, &format!("{path}.{i}"), found);
+            }
+        }
+        (Value::List(a_items), Value::List(e_items)) => list_diff(a_items, e_items, path, found),
+        (Value::Map(a_entries), Value::Map(e_entries)) => {
+            let mut entries = Vec::new();
+            for (key, a) in a_entries {
+                let at = format!("{path}[{}]", key.render());
+                match e_entries.iter().find(|(k, _)| k == key) {
+                    Some((_, e)) => diff(a, e, &at, &mut entries),
+                    None => entries.push(only_actual(at, a)),
+                }
+            }
+            for (key, e) in e_entries {
+                if !a_entries.iter().any(|(k, _)| k == key) {
+                    entries.push(only_expected(format!("{path}[{}]", key.render()), e));
+                }
+            }
+            // `HashMap`s render in no particular order.
+            entries.sort_by(|x, y| x.path().cmp(y.path()));
+            found.extend(entries);
+        }
+        (Value::Set(a_items), Value::Set(e_items)) => set_diff(a_items, e_items, path, found),
+        (Value::Set(a_items), Value::Map(e_entries)) if e_entries.is_empty() => {
+            set_diff(a_items, &[], path, found)
+        }
+        (Value::Map(a_entries), Value::Set(e_items)) if a_entries.is_empty() => {
+            set_diff(&[], e_items, path, found)
+        }
+        (a, e) if a != e => found.push(Change::new(
+            path.to_string(),
+            Some(a.render()),
+            Some(e.render()),
+        )),
+        _ => {}
+    }
+}
+
+/// Compares lists item by item if they're the same length. Otherwise
+/// items only in one of them are reported as such, except that runs of
+/// removed items directly followed by added ones are compared pairwise,
+/// as changed items.
+fn list_diff(actual: &[Value], expected: &[Value], path: &str, found: &mut Vec<Change>) {
+    if actual.len() == expected.len() {
+        for (i, (a, e)) in actual.iter().zip(expected).enumerate() {
+            diff(a, e, &format!("{path}[{i}]"), found);
+        }
+        return;
+    }
+    let (mut deleted, mut inserted) = (Vec::new(), Vec::new());
+    let mut flush = |deleted: &mut Vec<usize>, inserted: &mut Vec<usize>| {
+        for k in 0..deleted.len().max(inserted.len()) {
+            match (deleted.get(k), inserted.get(k)) {
+                (Some(&i), Some(&j)) => {
+                    diff(&actual[i], &expected[j], &format!("{path}[{i}]"), found)
+                }
+                (Some(&i), None) => found.push(only_actual(format!("{path}[{i}]"), &actual[i])),
+                (None, Some(&j)) => found.push(only_expected(format!("{path}[{j}]"), &expected[j])),
+                (None, None) => {}
+            }
+        }
+        deleted.clear();
+        inserted.clear();
+    };
+    for edit in edit_script(actual, expected) {
+        match edit {
+            Edit::Delete(i) => deleted.push(i),
+            Edit::Insert(j) => inserted.push(j),
+            Edit::Equal(..) => flush(&mut deleted, &mut inserted),
+        }
+    }
+    flush(&mut deleted, &mut inserted);
+}
+
+/// Matches elements one to one, so that an element occurring more often
+/// on one side is reported as extra or missing.
+fn set_diff(actual: &[Value], expected: &[Value], path: &str, found: &mut Vec<Change>) {
+    let mut unmatched: Vec<&Value> = expected.iter().collect();
+    let mut items = Vec::new();
+    for a in actual {
+        match unmatched.iter().position(|e| *e == a) {
+            Some(i) => {
+                unmatched.swap_remove(i);
+            }
+            None => items.push(only_actual(path.to_string(), a)),
+        }
+    }
+    items.extend(
+        unmatched
+            .into_iter()
+            .map(|e| only_expected(path.to_string(), e)),
+    );
+    // `HashSet`s render in no particular order.
+    items.sort_by_key(|change| {
+        let (actual, expected) = (
+            change.actual().map(str::to_string),
+            change.expected().map(str::to_string),
+        );
+        (actual.is_none(), actual, expected)
+    });
+    found.extend(items);
+}
+
+fn only_actual(path: String, value: &Value) -> Change {
+    Change::new(path, Some(value.render()), None)
+}
+
+fn only_expected(path: String, value: &Value) -> Change {
+    Change::new(path, None, Some(value.render()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::{BTreeMap, HashMap, HashSet};
+
+    // The fields of these are only ever read through `Debug`.
+    #[allow(dead_code)]
+    #[derive(Debug)]
+    struct Line {
+        sku: &'static str,
+        qty: u32,
+    }
+
+    #[allow(dead_code)]
+    #[derive(Debug)]
+    struct Order {
+        id: u32,
+        lines: Vec<Line>,
+    }
+
+    #[allow(dead_code)]
+    #[derive(Debug)]
+    struct Customer {
+        orders: Vec<Order>,
+    }
+
+    fn described(actual: impl std::fmt::Debug, expected: impl std::fmt::Debug) -> Vec<String> {
+        let actual = parse(&format!("{actual:?}")).unwrap();
+        let expected = parse(&format!("{expected:?}")).unwrap();
+        differences(&actual, &expected)
+            .iter()
+            .map(Change::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn renderings_round_trip() {
+        #[derive(Debug)]
+        struct Unit;
+        #[allow(dead_code)]
+        #[derive(Debug)]
+        enum Shape {
+            Circle { r: f64 },
+            Pair(i8, i8),
+        }
+        let renderings = [
+            format!("{:?}", Shape::Circle { r: -1.5 }),
+            format!("{:?}", Shape::Pair(1, -2)),
+            format!("{:?}", (Unit, "a, \"b\"", 'c', '\'', 1..3, (1,))),
+            format!("{:?}", Some(vec![Ok::<_, ()>(None::<u8>)])),
+            format!("{:?}", BTreeMap::from([(1, "x"), (2, "y")])),
+            format!("{:?}", HashSet::from([7])),
+            format!("{:?}", std::time::Duration::from_millis(1500)),
+        ];
+        for rendering in renderings {
+            assert_eq!(parse(&rendering).unwrap().render(), rendering);
+        }
+    }
+
+    #[test]
+    fn pretty_renderings_parse_to_the_same_tree() {
+        let value = Customer {
+            orders: vec![Order {
+                id: 1,
+                lines: vec![],
+            }],
+        };
+        assert_eq!(parse(&format!("{value:#?}")), parse(&format!("{value:?}")));
+    }
+
+    #[test]
+    fn malformed_renderings_are_rejected() {
+        assert_eq!(parse("Foo { a 1 }"), None);
+        assert_eq!(parse("[1, 2"), None);
+        assert_eq!(parse("(1) 2"), None);
+    }
+
+    #[test]
+    fn deeply_nested_renderings_are_rejected() {
+        let nested = |depth| format!("{}1{}", "[".repeat(depth), "]".repeat(depth));
+        assert!(parse(&nested(MAX_DEPTH - 1)).is_some());
+        assert_eq!(parse(&nested(MAX_DEPTH)), None);
+        assert_eq!(describe_differences(&nested(10_000), &nested(10_001)), None);
+    }
+
+    #[test]
+    fn long_renderings_are_not_described() {
+        let list = |last| {
+            format!(
+                "{:?}",
+                (0..MAX_DESCRIBED_LEN).chain([last]).collect::<Vec<_>>()
+            )
+        };
+        assert_eq!(describe_differences(&list(1), &list(2)), None);
+        assert!(describe_differences("[1, 2]", "[1, 3]").is_some());
+    }
+
+    #[test]
+    fn nested_difference_is_named_by_path() {
+        let customer = |qty| Customer {
+            orders: vec![Order {
+                id: 1,
+                lines: vec![Line { sku: "a", qty: 1 }, Line { sku: "b", qty }],
+            }],
+        };
+        assert_eq!(
+            described(customer(2), customer(3)),
+            [".orders[0].lines[1].qty: 2 != 3"]
+        );
+    }
+
+    #[test]
+    fn different_variants_differ_as_a_whole() {
+        assert_eq!(
+            described(vec![Some(1), None], vec![Some(2), Some(3)]),
+            ["[0].0: 1 != 2", "[1]: None != Some(3)"]
+        );
+    }
+
+    #[test]
+    fn inserted_and_removed_items_are_named() {
+        assert_eq!(
+            described(vec![1, 2, 3, 4], vec![1, 3, 5, 4, 6]),
+            ["[1]: extra 2", "[2]: missing 5", "[4]: missing 6"]
+        );
+        assert_eq!(
+            described(
+                vec![Line { sku: "a", qty: 1 }],
+                vec![Line { sku: "b", qty: 1 }, Line { sku: "c", qty: 2 }]
+            ),
+            [
+                "[0].sku: \"a\" != \"b\"",
+                "[1]: missing Line { sku: \"c\", qty: 2 }"
+            ]
+        );
+    }
+
+    #[test]
+    fn map_entries_are_matched_by_key_in_any_order() {
+        let actual = HashMap::from([("a", 1), ("b", 2), ("c", 3)]);
+        let expected = HashMap::from([("c", 3), ("b", 20), ("d", 4)]);
+        assert_eq!(
+            described(actual, expected),
+            ["[\"a\"]: extra 1", "[\"b\"]: 2 != 20", "[\"d\"]: missing 4"]
+        );
+    }
+
+    #[test]
+    fn set_elements_are_matched_in_any_order() {
+        let actual = HashSet::from([1, 2, 3]);
+        let expected = HashSet::from([3, 4, 1]);
+        assert_eq!(described(actual, expected), ["extra 2", "missing 4"]);
+        assert_eq!(
+            described(HashSet::<u8>::new(), HashSet::from([1])),
+            ["missing 1"]
+        );
+    }
+
+    #[test]
+    fn set_elements_are_matched_one_to_one() {
+        assert_eq!(
+            differences(&parse("{1, 1, 2}").unwrap(), &parse("{1, 2, 2}").unwrap())
+                .iter()
+                .map(Change::to_string)
+                .collect::<Vec<_>>(),
+            ["extra 1", "missing 2"]
+        );
+    }
+
+    #[test]
+    fn non_exhaustive_structs_differ_in_their_fields_only() {
+        struct Partial(bool, u32);
+        impl std::fmt::Debug for Partial {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                let mut s = f.debug_struct("Partial");
+                s.field("id", &self.1);
+                if self.0 {
+                    s.finish_non_exhaustive()
+                } else {
+                    s.finish()
+                }
+            }
+        }
+        assert_eq!(
+            described(Partial(true, 1), Partial(false, 2)),
+            [".id: 1 != 2"]
+        );
+        assert_eq!(
+            described(Partial(false, 1), Partial(true, 1)),
+            Vec::<String>::new()
+        );
+    }
+
+    #[test]
+    fn equal_values_have_no_differences() {
+        let actual = HashMap::from([(1, vec!["x"]), (2, vec![]), (3, vec!["y", "z"])]);
+        assert_eq!(described(&actual, actual.clone()), Vec::<String>::new());
+    }
+}
@@ -4,11 +4,20 @@
 //! output of `{:?}`) and know nothing about `Assert` or any assertion
 //! semantics, so they can be tested directly with plain strings.
 
-/// The byte index and the two characters at that index where `a` and
-/// `b` first diverge. `None` on either side means that side's string
-/// ended first (a strict-prefix case).
+/// Where `a` and `b` first diverge, and the two characters there.
+/// `None` on either side means that side's string ended first (a
+/// strict-prefix case).
 pub(crate) struct Difference {
+    /// Byte offset of the first differing character.
     pub(crate) index: usize,
+    /// Number of characters before the first differing one.
+    pub(crate) char_index: usize,
+    /// Number of grapheme clusters before the one holding the first
+    /// differing character.
+    pub(crate) grapheme_index: usize,
+    /// Terminal columns taken up by those grapheme clusters, i.e. how far
+    /// to indent a caret so it sits under the differing glyph.
+    pub(crate) column: usize,
     pub(crate) actual: Option<char>,
     pub(crate) expected: Option<char>,
 }
@@ -19,57 +28,368 @@ pub(crate) fn first_difference(a: &str, b: &str) -> Option<Difference> {
     let mut a_iter = a.char_indices();
     let mut b_iter = b.chars();
 
-    loop {
+    let (mut index, mut actual, mut expected) = loop {
         match (a_iter.next(), b_iter.next()) {
             (Some((index, ca)), Some(cb)) => {
                 if ca != cb {
-                    return Some(Difference {
-                        index,
-                        actual: Some(ca),
-                        expected: Some(cb),
-                    });
+                    break (index, Some(ca), Some(cb));
                 }
             }
-            (Some((index, ca)), None) => {
-                return Some(Difference {
-                    index,
-                    actual: Some(ca),
-                    expected: None,
-                });
-            }
-            (None, Some(cb)) => {
-                return Some(Difference {
-                    index: a.len(),
-                    actual: None,
-                    expected: Some(cb),
-                });
-            }
+            (Some((index, ca)), None) => break (index, Some(ca), None),
+            (None, Some(cb)) => break (a.len(), None, Some(cb)),
             (None, None) => return None,
         }
+    };
+
+    // In `{:?}` output, a difference inside an escape sequence such as
+    // `\u{a0}` is a difference in the character it stands for.
+    if let Some(start) = escape_start(a, index) {
+        index = start;
+        actual = unescape(&a[start..]).or(actual);
+        expected = unescape(&b[start..]).or(expected);
+    } else {
+        actual = actual.and_then(|c| unescape(&a[index..]).or(Some(c)));
+        expected = expected.and_then(|c| unescape(&b[index..]).or(Some(c)));
+    }
+
+    // The grapheme holding the differing character may start before it,
+    // e.g. when only a combining accent differs.
+    let mut grapheme_index = 0;
+    let mut grapheme_start = 0;
+    for grapheme in crate::unicode::graphemes(a) {
+        if grapheme_start + grapheme.len() > index {
+            break;
+        }
+        grapheme_index += 1;
+        grapheme_start += grapheme.len();
+    }
+    Some(Difference {
+        index,
+        char_index: a[..index].chars().count(),
+        grapheme_index,
+        column: crate::unicode::display_width(&a[..grapheme_start]),
+        actual,
+        expected,
+    })
+}
+
+/// Length in bytes of the `{:?}` escape sequence (e.g. `\n`, `\"` or
+/// `\u{200d}`) at the start of `text`, if there is one.
+fn escape_len(text: &str) -> Option<usize> {
+    let rest = text.strip_prefix('\\')?;
+    match rest.chars().next()? {
+        't' | 'r' | 'n' | '0' | '\\' | '\'' | '"' => Some(2),
+        'u' if rest[1..].starts_with('{') => Some(rest.find('}')? + 2),
+        _ => None,
+    }
+}
+
+/// The character that the `{:?}` escape sequence at the start of `text`
+/// stands for.
+fn unescape(text: &str) -> Option<char> {
+    let sequence = &text[1..escape_len(text)?];
+    match sequence {
+        "t" => Some('\t'),
+        "r" => Some('\r'),
+        "n" => Some('\n'),
+        "0" => Some('\0'),
+        _ if sequence.starts_with('u') => {
+            let hex = sequence.strip_prefix("u{")?.strip_suffix('}')?;
+            char::from_u32(u32::from_str_radix(hex, 16).ok()?)
+        }
+        _ => sequence.chars().next(),
+    }
+}
+
+/// The start of the escape sequence in `text` that byte `index` lies
+/// inside of (but not at the start of), if any.
+fn escape_start(text: &str, index: usize) -> Option<usize> {
+    let mut position = 0;
+    while position < index {
+        let len = escape_len(&text[position..])
+            .unwrap_or_else(|| text[position..].chars().next().map_or(1, char::len_utf8));
+        if position + len > index {
+            return Some(position);
+        }
+        position += len;
+    }
+    None
+}
+
+/// Renders `c` for a failure message: whitespace by its visible marker
+/// (`'·' (space)`), invisible and lookalike characters by codepoint
+/// (`U+00A0 (no-break space)`), anything else as a quoted character.
+pub(crate) fn describe_char(c: char) -> String {
+    let name = match c {
+        ' ' => "space",
+        '\t' => "tab",
+        '\r' => "carriage return",
+        '\n' => "line feed",
+        _ if crate::unicode::is_invisible(c) => {
+            let codepoint = format!("U+{:04X}", c as u32);
+            return match crate::unicode::invisible_name(c) {
+                Some(name) => format!("{codepoint} ({name})"),
+                None => codepoint,
+            };
+        }
+        _ => return format!("{c:?}"),
+    };
+    let marker = crate::unicode::marker(c).unwrap_or(c);
+    format!("'{marker}' ({name})")
+}
+
+/// Renders `text` with every whitespace character replaced by its
+/// visible marker (`·`, `→`, `␍`, `␊`) and every invisible or lookalike
+/// character by its codepoint, e.g. `<U+00A0>`.
+pub(crate) fn visible(text: &str) -> String {
+    let mut rendered = String::with_capacity(text.len());
+    for c in text.chars() {
+        match crate::unicode::marker(c) {
+            Some(marker) => rendered.push(marker),
+            None if crate::unicode::is_invisible(c) => {
+                rendered.push_str(&format!("<U+{:04X}>", c as u32));
+            }
+            None => rendered.push(c),
+        }
     }
+    rendered
 }
 
 /// Renders a human-readable description of a [`Difference`], e.g.
-/// `"differs at byte 8 ('p' vs 'o')"` or, for a length mismatch,
-/// `"actual has extra content at byte 12, starting with 'x'"`.
+/// `"differs at character 8 ('p' vs 'o')"` or, for a length mismatch,
+/// `"actual has extra content at character 12, starting with 'x'"`. The
+/// grapheme position is added where it differs from the character one,
+/// and whitespace and invisible characters are spelled out, see
+/// [describe_char].
 pub(crate) fn describe(diff: &Difference) -> String {
+    let position = if diff.grapheme_index == diff.char_index {
+        format!("character {}", diff.char_index)
+    } else {
+        format!(
+            "character {} (grapheme {})",
+            diff.char_index, diff.grapheme_index
+        )
+    };
     match (diff.actual, diff.expected) {
-        (Some(a), Some(b)) => {
-            format!("differs at byte {} ({a:?} vs {b:?})", diff.index)
+        (Some(a), Some(b)) => format!(
+            "differs at {position} ({} vs {})",
+            describe_char(a),
+            describe_char(b)
+        ),
+        (Some(a), None) => format!(
+            "actual has extra content at {position}, starting with {}",
+            describe_char(a)
+        ),
+        (None, Some(b)) => format!(
+            "expected has extra content at {position}, starting with {}",
+            describe_char(b)
+        ),
+        (None, None) => unreachable!("Difference always has at least one side set"),
+    }
+}
+
+/// One step of an edit script turning `a` into `b`, see [edit_script].
+#[derive(Debug, PartialEq)]
+pub(crate) enum Edit {
+    /// `a[i] == b[j]`.
+    Equal(usize, usize),
+    /// `a[i]` is not in `b`.
+    Delete(usize),
+    /// `b[j]` is not in `a`.
+    Insert(usize),
+}
+
+/// Above this many LCS table cells (after trimming the common prefix and
+/// suffix), [edit_script] gives up on finding a minimal script and just
+/// replaces the whole differing middle part.
+const MAX_LCS_CELLS: usize = 4_000_000;
+
+/// A shortest edit script turning `a` into `b`, based on their longest
+/// common subsequence. Deletions come before insertions wherever both
+/// are possible, as in `diff -u` output.
+pub(crate) fn edit_script<T: PartialEq>(a: &[T], b: &[T]) -> Vec<Edit> {
+    let prefix = a.iter().zip(b).take_while(|(x, y)| x == y).count();
+    let suffix = a[prefix..]
+        .iter()
+        .rev()
+        .zip(b[prefix..].iter().rev())
+        .take_while(|(x, y)| x == y)
+        .count();
+    let (a_mid, b_mid) = (&a[prefix..a.len() - suffix], &b[prefix..b.len() - suffix]);
+    let (n, m) = (a_mid.len(), b_mid.len());
+
+    let mut script: Vec<Edit> = (0..prefix).map(|i| Edit::Equal(i, i)).collect();
+    if n.saturating_mul(m) > MAX_LCS_CELLS {
+        script.extend((0..n).map(|i| Edit::Delete(prefix + i)));
+        script.extend((0..m).map(|j| Edit::Insert(prefix + j)));
+    } else {
+        // lcs[i][j] is the LCS length of a_mid[i..] and b_mid[j..].
+        let mut lcs = vec![vec![0u32; m + 1]; n + 1];
+        for i in (0..n).rev() {
+            for j in (0..m).rev() {
+                lcs[i][j] = if a_mid[i] == b_mid[j] {
+                    lcs[i + 1][j + 1] + 1
+                } else {
+                    lcs[i + 1][j].max(lcs[i][j + 1])
+                };
+            }
         }
-        (Some(a), None) => {
-            format!(
-                "actual has extra content at byte {}, starting with {a:?}",
-                diff.index
-            )
+        let (mut i, mut j) = (0, 0);
+        while i < n || j < m {
+            if i < n && j < m && a_mid[i] == b_mid[j] {
+                script.push(Edit::Equal(prefix + i, prefix + j));
+                i += 1;
+                j += 1;
+            } else if i < n && (j == m || lcs[i + 1][j] >= lcs[i][j + 1]) {
+                script.push(Edit::Delete(prefix + i));
+                i += 1;
+            } else {
+                script.push(Edit::Insert(prefix + j));
+                j += 1;
+            }
         }
-        (None, Some(b)) => {
-            format!(
-                "expected has extra content at byte {}, starting with {b:?}",
-                diff.index
-            )
+    }
+    script.extend((0..suffix).map(|k| Edit::Equal(a.len() - suffix + k, b.len() - suffix + k)));
+    script
+}
+
+/// Renders a character-level diff of `actual` against `expected`
+/// inline, marking every deleted run as `[-…-]` and every inserted run
+/// as `{+…+}`, e.g. `"hello w[-p-]{+o+}rld"`. Whitespace in those runs
+/// is shown by its [visible] marker. Returns `None` if the two
+/// have nothing in common, as the markup would then only repeat both
+/// values.
+pub(crate) fn inline_diff(actual: &str, expected: &str) -> Option<String> {
+    let a: Vec<char> = actual.chars().collect();
+    let b: Vec<char> = expected.chars().collect();
+    let script = edit_script(&a, &b);
+    if !script.iter().any(|edit| matches!(edit, Edit::Equal(..))) {
+        return None;
+    }
+    let mut rendered = String::new();
+    let (mut deleted, mut inserted) = (String::new(), String::new());
+    for edit in script {
+        match edit {
+            Edit::Delete(i) => deleted.push(a[i]),
+            Edit::Insert(j) => inserted.push(b[j]),
+            Edit::Equal(i, _) => {
+                flush_changes(&mut rendered, &mut deleted, &mut inserted);
+                rendered.push(a[i]);
+            }
         }
-        (None, None) => unreachable!("Difference always has at least one side set"),
+    }
+    flush_changes(&mut rendered, &mut deleted, &mut inserted);
+    Some(rendered)
+}
+
+/// Appends the pending `deleted` and `inserted` runs of [inline_diff] to
+/// `rendered`, leaving both empty.
+fn flush_changes(rendered: &mut String, deleted: &mut String, inserted: &mut String) {
+    if !deleted.is_empty() {
+        rendered.push_str(&format!("[-{}-]", visible(deleted)));
+        deleted.clear();
+    }
+    if !inserted.is_empty() {
+        rendered.push_str(&format!("{{+{}+}}", visible(inserted)));
+        inserted.clear();
+    }
+}
+
+/// Splits a `{:#?}` rendering into lines: at newlines, and also right
+/// after each `\n` escape so that multi-line strings are diffed line by
+/// line rather than as one long line.
+pub(crate) fn split_lines(text: &str) -> Vec<&str> {
+    let mut lines = Vec::new();
+    let mut start = 0;
+    let mut escaped = false;
+    for (index, c) in text.char_indices() {
+        match c {
+            '\n' => {
+                lines.push(&text[start..index]);
+                start = index + 1;
+            }
+            'n' if escaped => {
+                lines.push(&text[start..=index]);
+                start = index + 1;
+            }
+            _ => {}
+        }
+        escaped = c == '\\' && !escaped;
+    }
+    lines.push(&text[start..]);
+    lines
+}
+
+/// Number of unchanged lines shown around each change in [unified_diff].
+const CONTEXT_LINES: usize = 3;
+
+/// Renders a line-based unified diff (`-` actual, `+` expected) of
+/// `actual` and `expected`, with every changed hunk and up to
+/// [CONTEXT_LINES] lines of context around each. Lines are indented by
+/// two spaces to line up with the rest of a failure message.
+pub(crate) fn unified_diff(actual: &[&str], expected: &[&str]) -> String {
+    let script = edit_script(actual, expected);
+    let changes: Vec<usize> = script
+        .iter()
+        .enumerate()
+        .filter(|(_, edit)| !matches!(edit, Edit::Equal(..)))
+        .map(|(k, _)| k)
+        .collect();
+
+    // Group changes closer than 2 * CONTEXT_LINES into the same hunk.
+    let mut hunks: Vec<(usize, usize)> = Vec::new();
+    for &k in &changes {
+        let start = k.saturating_sub(CONTEXT_LINES);
+        let end = (k + CONTEXT_LINES + 1).min(script.len());
+        match hunks.last_mut() {
+            Some((_, last_end)) if start <= *last_end => *last_end = end,
+            _ => hunks.push((start, end)),
+        }
+    }
+
+    let mut diff = String::from("  --- actual\n  +++ expected");
+    for (start, end) in hunks {
+        let edits = &script[start..end];
+        let (mut a_start, mut a_len, mut b_start, mut b_len) = (None, 0, None, 0);
+        let mut body = String::new();
+        for edit in edits {
+            match *edit {
+                Edit::Equal(i, j) => {
+                    a_start.get_or_insert(i);
+                    b_start.get_or_insert(j);
+                    a_len += 1;
+                    b_len += 1;
+                    body.push_str(&format!("\n   {}", actual[i]));
+                }
+                Edit::Delete(i) => {
+                    a_start.get_or_insert(i);
+                    a_len += 1;
+                    body.push_str(&format!("\n  -{}", actual[i]));
+                }
+                Edit::Insert(j) => {
+                    b_start.get_or_insert(j);
+                    b_len += 1;
+                    body.push_str(&format!("\n  +{}", expected[j]));
+                }
+            }
+        }
+        diff.push_str(&format!(
+            "\n  @@ -{} +{} @@{body}",
+            hunk_range(a_start, a_len),
+            hunk_range(b_start, b_len),
+        ));
+    }
+    diff
+}
+
+/// Renders one side of a `@@ -l,s +l,s @@` hunk header. `start` is the
+/// 0-based first line of that side in the hunk, if it has any lines (it
+/// only has none if that whole side is empty).
+fn hunk_range(start: Option<usize>, len: usize) -> String {
+    match (start, len) {
+        (Some(start), 1) => (start + 1).to_string(),
+        (Some(start), len) => format!("{},{len}", start + 1),
+        (None, _) => String::from("0,0"),
     }
 }
 
@@ -121,10 +441,34 @@ mod tests {
         assert_eq!(diff.expected, Some('o'));
     }
 
+    #[test]
+    fn position_after_multi_byte_characters() {
+        let diff = first_difference("héllo", "héllx").unwrap();
+        assert_eq!(diff.index, 5);
+        assert_eq!(diff.char_index, 4);
+        assert_eq!(diff.grapheme_index, 4);
+        assert_eq!(diff.column, 4);
+    }
+
+    #[test]
+    fn column_counts_wide_characters_twice() {
+        let diff = first_difference("日本語!", "日本語?").unwrap();
+        assert_eq!(diff.char_index, 3);
+        assert_eq!(diff.column, 6);
+    }
+
+    #[test]
+    fn combining_accent_difference_points_at_its_base() {
+        let diff = first_difference("ae\u{301}", "ae\u{300}").unwrap();
+        assert_eq!(diff.char_index, 2);
+        assert_eq!(diff.grapheme_index, 1);
+        assert_eq!(diff.column, 1);
+    }
+
     #[test]
     fn describe_formats_char_difference() {
         let diff = first_difference("abc", "abx").unwrap();
-        assert_eq!(describe(&diff), "differs at byte 2 ('c' vs 'x')");
+        assert_eq!(describe(&diff), "differs at character 2 ('c' vs 'x')");
     }
 
     #[test]
@@ -132,7 +476,7 @@ mod tests {
         let diff = first_difference("abcd", "abc").unwrap();
         assert_eq!(
             describe(&diff),
-            "actual has extra content at byte 3, starting with 'd'"
+            "actual has extra content at character 3, starting with 'd'"
         );
     }
 
@@ -141,7 +485,186 @@ mod tests {
         let diff = first_difference("abc", "abcd").unwrap();
         assert_eq!(
             describe(&diff),
-            "expected has extra content at byte 3, starting with 'd'"
+            "expected has extra content at character 3, starting with 'd'"
+        );
+    }
+
+    #[test]
+    fn describe_adds_grapheme_position_where_it_differs() {
+        let diff = first_difference("👍🏽x", "👍🏽y").unwrap();
+        assert_eq!(
+            describe(&diff),
+            "differs at character 2 (grapheme 1) ('x' vs 'y')"
+        );
+    }
+
+    #[test]
+    fn edit_script_of_identical_sequences_is_all_equal() {
+        assert_eq!(
+            edit_script(&[1, 2], &[1, 2]),
+            vec![Edit::Equal(0, 0), Edit::Equal(1, 1)]
+        );
+    }
+
+    #[test]
+    fn edit_script_finds_replacement_in_the_middle() {
+        assert_eq!(
+            edit_script(&[1, 2, 3], &[1, 9, 3]),
+            vec![
+                Edit::Equal(0, 0),
+                Edit::Delete(1),
+                Edit::Insert(1),
+                Edit::Equal(2, 2)
+            ]
+        );
+    }
+
+    #[test]
+    fn edit_script_finds_insertions_and_deletions() {
+        assert_eq!(
+            edit_script(&['a', 'b', 'c', 'd'], &['b', 'c', 'x', 'd']),
+            vec![
+                Edit::Delete(0),
+                Edit::Equal(1, 0),
+                Edit::Equal(2, 1),
+                Edit::Insert(2),
+                Edit::Equal(3, 3)
+            ]
+        );
+    }
+
+    #[test]
+    fn split_lines_splits_at_newlines_and_newline_escapes() {
+        assert_eq!(split_lines("a {\n    b\n}"), vec!["a {", "    b", "}"]);
+        assert_eq!(split_lines(r#""one\ntwo""#), vec![r#""one\n"#, r#"two""#]);
+    }
+
+    #[test]
+    fn split_lines_ignores_escaped_backslash_before_n() {
+        assert_eq!(split_lines(r#""a\\nb""#), vec![r#""a\\nb""#]);
+    }
+
+    #[test]
+    fn unified_diff_reports_every_hunk_with_context() {
+        let actual: Vec<String> = (1..=20).map(|n| n.to_string()).collect();
+        let mut expected = actual.clone();
+        expected[1] = String::from("two");
+        expected[15] = String::from("sixteen");
+        let actual: Vec<&str> = actual.iter().map(String::as_str).collect();
+        let expected: Vec<&str> = expected.iter().map(String::as_str).collect();
+        assert_eq!(
+            unified_diff(&actual, &expected),
+            "  --- actual\n  +++ expected\
+             \n  @@ -1,5 +1,5 @@\n   1\n  -2\n  +two\n   3\n   4\n   5\
+             \n  @@ -13,7 +13,7 @@\n   13\n   14\n   15\n  -16\n  +sixteen\n   17\n   18\n   19"
+        );
+    }
+
+    #[test]
+    fn unified_diff_reports_insertion() {
+        assert_eq!(
+            unified_diff(&["a", "b"], &["a", "x", "b"]),
+            "  --- actual\n  +++ expected\n  @@ -1,2 +1,3 @@\n   a\n  +x\n   b"
+        );
+    }
+
+    #[test]
+    fn unified_diff_against_empty_side() {
+        assert_eq!(
+            unified_diff(&[], &["a", "b"]),
+            "  --- actual\n  +++ expected\n  @@ -0,0 +1,2 @@\n  +a\n  +b"
+        );
+    }
+
+    #[test]
+    fn inline_diff_marks_replacement() {
+        assert_eq!(
+            inline_diff("hello wprld", "hello world").as_deref(),
+            Some("hello w[-p-]{+o+}rld")
+        );
+    }
+
+    #[test]
+    fn inline_diff_shows_every_divergence() {
+        assert_eq!(
+            inline_diff("[1, 2, 3, 4]", "[1, 3, 4, 5]").as_deref(),
+            Some("[1, [-2,·-]3, 4{+,·5+}]")
+        );
+    }
+
+    #[test]
+    fn inline_diff_marks_single_insertion() {
+        assert_eq!(
+            inline_diff("hello wrld", "hello world").as_deref(),
+            Some("hello w{+o+}rld")
+        );
+    }
+
+    #[test]
+    fn inline_diff_handles_multibyte_characters() {
+        assert_eq!(inline_diff("aée", "aèe").as_deref(), Some("a[-é-]{+è+}e"));
+    }
+
+    #[test]
+    fn inline_diff_is_none_without_common_characters() {
+        assert_eq!(inline_diff("12", "34"), None);
+    }
+
+    #[test]
+    fn difference_inside_escape_is_reported_as_escaped_character() {
+        let diff = first_difference(r#""a\tb""#, r#""a\nb""#).unwrap();
+        assert_eq!(diff.index, 2);
+        assert_eq!(diff.char_index, 2);
+        assert_eq!(diff.actual, Some('\t'));
+        assert_eq!(diff.expected, Some('\n'));
+    }
+
+    #[test]
+    fn difference_at_unicode_escape_is_reported_as_escaped_character() {
+        let diff = first_difference(r#""a\u{a0}b""#, r#""a b""#).unwrap();
+        assert_eq!(diff.index, 2);
+        assert_eq!(diff.actual, Some('\u{a0}'));
+        assert_eq!(diff.expected, Some(' '));
+        assert_eq!(
+            describe(&diff),
+            "differs at character 2 (U+00A0 (no-break space) vs '·' (space))"
+        );
+    }
+
+    #[test]
+    fn escaped_backslash_is_not_an_escape_start() {
+        let diff = first_difference(r#""\\n""#, r#""\\t""#).unwrap();
+        assert_eq!(diff.index, 3);
+        assert_eq!(diff.actual, Some('n'));
+    }
+
+    #[test]
+    fn describe_marks_whitespace() {
+        let diff = first_difference("a\t", "a ").unwrap();
+        assert_eq!(
+            describe(&diff),
+            "differs at character 1 ('→' (tab) vs '·' (space))"
+        );
+        let diff = first_difference("ab ", "ab").unwrap();
+        assert_eq!(
+            describe(&diff),
+            "actual has extra content at character 2, starting with '·' (space)"
+        );
+    }
+
+    #[test]
+    fn visible_marks_whitespace_and_invisible_characters() {
+        assert_eq!(
+            visible("a b\tc\r\n\u{a0}\u{200d}"),
+            "a·b→c␍␊<U+00A0><U+200D>"
+        );
+    }
+
+    #[test]
+    fn inline_diff_marks_whitespace_in_changes() {
+        assert_eq!(
+            inline_diff("\"trailing \"", "\"trailing\"").as_deref(),
+            Some("\"trailing[-·-]\"")
         );
     }
 }
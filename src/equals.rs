@@ -1,4 +1,5 @@
 use crate::Assert;
+use std::cmp::Ordering;
 use std::fmt::Debug;
 
 /// Length of the `"  Actual:   \`"` / `"  Expected: \`"` prefixes used in
@@ -7,12 +8,29 @@ use std::fmt::Debug;
 /// construction (checked by `value_prefixes_are_equal_length` below).
 const VALUE_PREFIX_LEN: usize = "  Actual:   `".len();
 
+/// Above this length, a `{:?}` rendering is too long to eyeball next to
+/// a single caret, so [`Assert::is`] shows a line-based diff of the
+/// `{:#?}` renderings instead.
+const LONG_RENDERING_LEN: usize = 80;
+
+/// Why an ordering assertion fails for values that `partial_cmp` can't
+/// order, like NaN, whether negated or not.
+pub(crate) const INCOMPARABLE: &str = "values are not comparable (partial_cmp returned None)";
+
 impl<T> Assert<T>
 where
     T: Debug,
 {
     /// Assert that `self` is equal to the `expected` value.
     ///
+    /// On failure, points at the first difference between the `{:?}`
+    /// renderings of both values and marks every deleted (`[-…-]`) and
+    /// inserted (`{+…+}`) run of characters in a `Diff` line. If either
+    /// rendering is long or spans several lines, it shows a unified diff
+    /// of their `{:#?}` renderings with every changed hunk instead. For
+    /// structs, tuples and collections it also lists the path to every
+    /// differing part, e.g. `.lines[0].qty: 2 != 3`.
+    ///
     /// ```
     /// # use assert4rs::Assert;
     /// Assert::that(2).is(2);
@@ -25,32 +43,162 @@ where
     /// Assert::that(2).is(3);
     /// ```
     #[track_caller]
-    pub fn is<R>(self, expected: R) -> Self
+    pub fn is<R>(mut self, expected: R) -> Self
     where
         T: PartialEq<R>,
         R: Debug,
     {
+        let negated = self.take_negation();
+        if negated {
+            if self.actual == expected {
+                self.fail(
+                    self.error("actual == expected")
+                        .negated(negated)
+                        .aligned_detail("Actual", format!("{:?}", self.actual), "Expected")
+                        .detail("Expected", format!("not {expected:?}")),
+                );
+            }
+            return self;
+        }
         if self.actual == expected {
             return self;
         }
         let actual_debug = format!("{:?}", self.actual);
         let expected_debug = format!("{:?}", expected);
-        let pointer = crate::diff::first_difference(&actual_debug, &expected_debug)
-            .map(|d| {
-                format!(
-                    "\n{}^ {}",
-                    // `d.index` is a byte offset into the `{:?}`-formatted string, so
-                    // the caret may visually misalign for debug output containing
-                    // multi-byte UTF-8 characters before the point of difference.
-                    " ".repeat(VALUE_PREFIX_LEN + d.index),
-                    crate::diff::describe(&d)
-                )
+        let difference = crate::diff::first_difference(&actual_debug, &expected_debug);
+        // Cut huge renderings down to the same window around the first
+        // difference, so the pointer still lines up.
+        let truncate = |text: &str| match (crate::truncate::max_len(), &difference) {
+            (Some(max_len), Some(d))
+                if actual_debug.chars().count() > max_len
+                    || expected_debug.chars().count() > max_len =>
+            {
+                let start = crate::truncate::window_start(d.char_index, max_len);
+                crate::truncate::window(text, start, max_len)
+            }
+            _ => text.to_string(),
+        };
+        let is_long = |debug: &str| {
+            debug.len() > LONG_RENDERING_LEN || crate::diff::split_lines(debug).len() > 1
+        };
+        let line_diff = if is_long(&actual_debug) || is_long(&expected_debug) {
+            let actual_pretty = format!("{:#?}", self.actual);
+            let expected_pretty = format!("{:#?}", expected);
+            let actual_lines = crate::diff::split_lines(&actual_pretty);
+            let expected_lines = crate::diff::split_lines(&expected_pretty);
+            (actual_lines.len() > 1 || expected_lines.len() > 1).then(|| {
+                crate::truncate::diff_lines(crate::diff::unified_diff(
+                    &actual_lines,
+                    &expected_lines,
+                ))
             })
-            .unwrap_or_default();
-        panic!(
-            "{}\n  Actual:   `{actual_debug}`\n  Expected: `{expected_debug}`{pointer}",
-            self.header("actual == expected"),
-        );
+        } else {
+            None
+        };
+        let actual_shown = truncate(&actual_debug);
+        let expected_shown = truncate(&expected_debug);
+        let diff = line_diff.or_else(|| {
+            let d = difference.as_ref()?;
+            let shown = crate::diff::first_difference(&actual_shown, &expected_shown)?;
+            let pointer = format!(
+                "{}^ {}",
+                " ".repeat(VALUE_PREFIX_LEN + shown.column),
+                crate::diff::describe(d)
+            );
+            Some(
+                match crate::diff::inline_diff(&actual_debug, &expected_debug) {
+                    Some(inline) => format!("{pointer}\n  Diff:     `{}`", truncate(&inline)),
+                    None => pointer,
+                },
+            )
+        });
+        let differences = crate::debug_tree::describe_differences(&actual_debug, &expected_debug);
+        let diff = match (diff, differences) {
+            (Some(diff), Some(differences)) => Some(format!("{diff}\n{differences}")),
+            (diff, differences) => diff.or(differences),
+        };
+        let mut error = self
+            .error("actual == expected")
+            .aligned_detail("Actual", actual_shown, "Expected")
+            .detail("Expected", expected_shown);
+        if let Some(diff) = diff {
+            error = error.with_diff(diff);
+        }
+        self.fail(error);
+        self
+    }
+
+    /// Assert that `self` renders the same as the `expected` value with
+    /// `{:?}`, comparing part by part: fields by name, list items in
+    /// order, and map entries and set elements in any order. Needs
+    /// nothing beyond [Debug], so it works for types without
+    /// [PartialEq], and for values of different types.
+    ///
+    /// On failure, lists the path to every differing part, e.g.
+    /// `.orders[3].lines[0].qty: 2 != 3`.
+    ///
+    /// ```
+    /// # use assert4rs::Assert;
+    /// # use std::collections::HashMap;
+    /// #[derive(Debug)]
+    /// struct Line { qty: u32 }
+    ///
+    /// Assert::that(vec![Line { qty: 2 }]).is_deep_eq(vec![Line { qty: 2 }]);
+    /// Assert::that(HashMap::from([(1, "a"), (2, "b")])).is_deep_eq(HashMap::from([(2, "b"), (1, "a")]));
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use assert4rs::Assert;
+    /// #[derive(Debug)]
+    /// struct Line { qty: u32 }
+    ///
+    /// // Assertion failed: `(actual.is_deep_eq(expected))`
+    /// //   Actual:   `[Line { qty: 2 }]`
+    /// //   Expected: `[Line { qty: 3 }]`
+    /// //   Differences:
+    /// //     [0].qty: 2 != 3
+    /// Assert::that(vec![Line { qty: 2 }]).is_deep_eq(vec![Line { qty: 3 }]);
+    /// ```
+    #[track_caller]
+    pub fn is_deep_eq<R: Debug>(mut self, expected: R) -> Self {
+        let negated = self.take_negation();
+        let actual_debug = format!("{:?}", self.actual);
+        let expected_debug = format!("{expected:?}");
+        let equal = match (
+            crate::debug_tree::parse(&actual_debug),
+            crate::debug_tree::parse(&expected_debug),
+        ) {
+            (Some(actual), Some(expected)) => {
+                crate::debug_tree::differences(&actual, &expected).is_empty()
+            }
+            _ => actual_debug == expected_debug,
+        };
+        if equal != negated {
+            return self;
+        }
+        let shown = |text: &str| match crate::truncate::max_len() {
+            Some(max_len) => crate::truncate::window(text, 0, max_len),
+            None => text.to_string(),
+        };
+        let mut error = self
+            .error("actual.is_deep_eq(expected)")
+            .negated(negated)
+            .aligned_detail("Actual", shown(&actual_debug), "Expected");
+        if negated {
+            error = error.detail("Expected", format!("not {}", shown(&expected_debug)));
+        } else {
+            error = error.detail("Expected", shown(&expected_debug));
+            let diff = crate::debug_tree::describe_differences(&actual_debug, &expected_debug)
+                .or_else(|| {
+                    crate::diff::inline_diff(&actual_debug, &expected_debug)
+                        .map(|inline| format!("  Diff:     `{}`", shown(&inline)))
+                });
+            if let Some(diff) = diff {
+                error = error.with_diff(diff);
+            }
+        }
+        self.fail(error);
+        self
     }
 
     /// Assert that `self` is not equal to the `other` value.
@@ -65,22 +213,26 @@ where
     /// Assert::that(2).is_not(2);
     /// ```
     #[track_caller]
-    pub fn is_not<R>(self, other: R) -> Self
+    pub fn is_not<R>(mut self, other: R) -> Self
     where
         T: PartialEq<R>,
         R: Debug,
     {
-        assert!(
-            self.actual != other,
-            "{}\n  Actual:   `{:?}`\n  Other:    `{:?}`",
-            self.header("actual != other"),
-            self.actual,
-            other,
-        );
+        let negated = self.take_negation();
+        if (self.actual != other) == negated {
+            self.fail(
+                self.error("actual != other")
+                    .negated(negated)
+                    .aligned_detail("Actual", format!("{:?}", self.actual), "Expected")
+                    .aligned_detail("Other", format!("{other:?}"), "Expected"),
+            );
+        }
         self
     }
 
-    /// Assert that `self` is greater than the `other` value.
+    /// Assert that `self` is greater than the `other` value. Values that
+    /// `partial_cmp` can't order, like NaN, fail this and its negation
+    /// alike.
     ///
     /// ```
     /// # use assert4rs::Assert;
@@ -96,23 +248,27 @@ where
     /// # use assert4rs::Assert;
     /// Assert::that(3).is_gt(4);
     /// ```
+    ///
+    /// ```should_panic
+    /// # use assert4rs::Assert;
+    /// // Assertion failed: `!(actual > other)`
+    /// //   Actual:   `NaN`
+    /// //   Other:    `1.0`
+    /// //   Reason:   `values are not comparable (partial_cmp returned None)`
+    /// Assert::that(f64::NAN).not().is_gt(1.0);
+    /// ```
     #[track_caller]
     pub fn is_gt<R>(self, other: R) -> Self
     where
         T: PartialOrd<R>,
         R: Debug,
     {
-        assert!(
-            self.actual > other,
-            "{}\n  Actual:   `{:?}`\n  Other:    `{:?}`",
-            self.header("actual > other"),
-            self.actual,
-            other,
-        );
-        self
+        self.orders("actual > other", other, Ordering::is_gt)
     }
 
     /// Assert that `self` is greater than or equal to the `other` value.
+    /// Values that `partial_cmp` can't order fail this and its negation
+    /// alike.
     ///
     /// ```
     /// # use assert4rs::Assert;
@@ -130,17 +286,11 @@ where
         T: PartialOrd<R>,
         R: Debug,
     {
-        assert!(
-            self.actual >= other,
-            "{}\n  Actual:   `{:?}`\n  Other:    `{:?}`",
-            self.header("actual >= other"),
-            self.actual,
-            other,
-        );
-        self
+        self.orders("actual >= other", other, Ordering::is_ge)
     }
 
-    /// Assert that `self` is less than the `other` value.
+    /// Assert that `self` is less than the `other` value. Values that
+    /// `partial_cmp` can't order fail this and its negation alike.
     ///
     /// ```
     /// # use assert4rs::Assert;
@@ -162,17 +312,12 @@ where
         T: PartialOrd<R>,
         R: Debug,
     {
-        assert!(
-            self.actual < other,
-            "{}\n  Actual:   `{:?}`\n  Other:    `{:?}`",
-            self.header("actual < other"),
-            self.actual,
-            other,
-        );
-        self
+        self.orders("actual < other", other, Ordering::is_lt)
     }
 
     /// Assert that `self` is less than or equal to the `other` value.
+    /// Values that `partial_cmp` can't order fail this and its negation
+    /// alike.
     ///
     /// ```
     /// # use assert4rs::Assert;
@@ -190,12 +335,112 @@ where
         T: PartialOrd<R>,
         R: Debug,
     {
-        assert!(
-            self.actual <= other,
-            "{}\n  Actual:   `{:?}`\n  Other:    `{:?}`",
-            self.header("actual <= other"),
-            self.actual,
-            other,
+        self.orders("actual <= other", other, Ordering::is_le)
+    }
+
+    /// Fails `assertion` unless `self` and `other` are ordered as
+    /// `accepted` allows, or if they can't be ordered at all.
+    #[track_caller]
+    fn orders<R>(mut self, assertion: &str, other: R, accepted: fn(Ordering) -> bool) -> Self
+    where
+        T: PartialOrd<R>,
+        R: Debug,
+    {
+        let negated = self.take_negation();
+        let ordering = self.actual.partial_cmp(&other);
+        if ordering.is_some_and(|ordering| accepted(ordering) != negated) {
+            return self;
+        }
+        let error = self
+            .error(assertion)
+            .negated(negated)
+            .aligned_detail("Actual", format!("{:?}", self.actual), "Expected")
+            .aligned_detail("Other", format!("{other:?}"), "Expected");
+        match ordering {
+            Some(_) => self.fail(error),
+            None => self.fail(error.aligned_detail("Reason", INCOMPARABLE.to_string(), "Expected")),
+        }
+        self
+    }
+
+    /// Assert that `partial_cmp` can order `self` and the `other` value,
+    /// to check a [PartialOrd] implementation directly.
+    ///
+    /// ```
+    /// # use assert4rs::Assert;
+    /// Assert::that(1.0).is_comparable_to(f64::INFINITY);
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use assert4rs::Assert;
+    /// Assert::that(1.0).is_comparable_to(f64::NAN);
+    /// ```
+    #[track_caller]
+    pub fn is_comparable_to<R>(mut self, other: R) -> Self
+    where
+        T: PartialOrd<R>,
+        R: Debug,
+    {
+        let negated = self.take_negation();
+        if self.actual.partial_cmp(&other).is_some() == negated {
+            self.fail(
+                self.error("actual.partial_cmp(other).is_some()")
+                    .negated(negated)
+                    .aligned_detail("Actual", format!("{:?}", self.actual), "Expected")
+                    .aligned_detail("Other", format!("{other:?}"), "Expected"),
+            );
+        }
+        self
+    }
+
+    /// Assert that `partial_cmp` orders `self` and the `other` value as
+    /// `expected`, to check a [PartialOrd] or [Ord] implementation
+    /// directly.
+    ///
+    /// ```
+    /// # use assert4rs::Assert;
+    /// use std::cmp::Ordering;
+    ///
+    /// Assert::that("apple").compares_as("banana", Ordering::Less);
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use assert4rs::Assert;
+    /// use std::cmp::Ordering;
+    ///
+    /// // Assertion failed: `(actual.partial_cmp(other) == expected)`
+    /// //   Actual:   `2`
+    /// //   Other:    `1`
+    /// //   Expected: `Less`
+    /// //   Found:    `Greater`
+    /// Assert::that(2).compares_as(1, Ordering::Less);
+    /// ```
+    #[track_caller]
+    pub fn compares_as<R>(mut self, other: R, expected: Ordering) -> Self
+    where
+        T: PartialOrd<R>,
+        R: Debug,
+    {
+        let negated = self.take_negation();
+        let ordering = self.actual.partial_cmp(&other);
+        if (ordering == Some(expected)) != negated {
+            return self;
+        }
+        let expected = match negated {
+            true => format!("not {expected:?}"),
+            false => format!("{expected:?}"),
+        };
+        let found = match ordering {
+            Some(ordering) => format!("{ordering:?}"),
+            None => INCOMPARABLE.to_string(),
+        };
+        self.fail(
+            self.error("actual.partial_cmp(other) == expected")
+                .negated(negated)
+                .aligned_detail("Actual", format!("{:?}", self.actual), "Expected")
+                .aligned_detail("Other", format!("{other:?}"), "Expected")
+                .detail("Expected", expected)
+                .aligned_detail("Found", found, "Expected"),
         );
         self
     }
@@ -212,13 +457,15 @@ where
     /// Assert::that(3).satisfies(|v| v % 2 == 0);
     /// ```
     #[track_caller]
-    pub fn satisfies(self, predicate: impl FnOnce(&T) -> bool) -> Self {
-        assert!(
-            predicate(&self.actual),
-            "{}\n  Actual: `{:?}`",
-            self.header("satisfies predicate"),
-            self.actual,
-        );
+    pub fn satisfies(mut self, predicate: impl FnOnce(&T) -> bool) -> Self {
+        let negated = self.take_negation();
+        if predicate(&self.actual) == negated {
+            self.fail(
+                self.error("satisfies predicate")
+                    .negated(negated)
+                    .detail("Actual", format!("{:?}", self.actual)),
+            );
+        }
         self
     }
 }
@@ -226,6 +473,7 @@ where
 #[cfg(test)]
 mod tests {
     use crate::Assert;
+    use std::cmp::Ordering;
 
     #[test]
     fn value_prefixes_are_equal_length() {
@@ -245,17 +493,35 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "differs at byte 0 ('1' vs '2')")]
+    #[should_panic(expected = "differs at character 0 ('1' vs '2')")]
     fn is_reports_diff_pointer() {
         Assert::that(1).is(2);
     }
 
     #[test]
-    #[should_panic(expected = "differs at byte 8 ('p' vs 'o')")]
+    #[should_panic(expected = "differs at character 8 ('p' vs 'o')")]
     fn is_reports_diff_pointer_for_strings() {
         Assert::that(String::from("hello wprld")).is("hello world");
     }
 
+    #[test]
+    #[should_panic(
+        expected = "^ differs at character 8 ('r' vs 'o')\n  Diff:     `\"hello w{+o+}rld{+!+}\"`"
+    )]
+    fn is_reports_every_divergence_inline() {
+        Assert::that(String::from("hello wrld")).is("hello world!");
+    }
+
+    #[test]
+    fn is_omits_inline_diff_without_common_characters() {
+        let result = std::panic::catch_unwind(|| {
+            Assert::that(12).is(34);
+        });
+        let message = result.unwrap_err();
+        let message = message.downcast_ref::<String>().unwrap();
+        assert!(!message.contains("Diff:"), "message: {message}");
+    }
+
     #[test]
     #[should_panic(expected = "Assertion failed: `(actual != other)`")]
     fn is_not_has_plain_header() {
@@ -278,7 +544,7 @@ mod tests {
         let message = result.unwrap_err();
         let message = message.downcast_ref::<String>().unwrap();
         assert!(
-            !message.contains("differs at byte"),
+            !message.contains("differs at character"),
             "unexpected diff pointer in: {message}"
         );
     }
@@ -328,7 +594,8 @@ mod tests {
         });
         let message = result.unwrap_err();
         let message = message.downcast_ref::<String>().unwrap();
-        let expected_pointer_line = format!("{}^ differs at byte 0 ('1' vs '2')", " ".repeat(13));
+        let expected_pointer_line =
+            format!("{}^ differs at character 0 ('1' vs '2')", " ".repeat(13));
         assert!(
             message.contains(&expected_pointer_line),
             "expected pointer line not found in: {message}"
@@ -336,20 +603,304 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "expected has extra content at byte 1, starting with '0'")]
+    #[should_panic(expected = "expected has extra content at character 1, starting with '0'")]
     fn is_reports_diff_for_expected_longer() {
         Assert::that(1).is(10);
     }
 
     #[test]
-    #[should_panic(expected = "actual has extra content at byte 1, starting with '0'")]
+    #[should_panic(expected = "actual has extra content at character 1, starting with '0'")]
     fn is_reports_diff_for_actual_longer() {
         Assert::that(10).is(1);
     }
 
     #[test]
-    #[should_panic(expected = "differs at byte 2 ('é' vs 'o')")]
+    #[should_panic(expected = "differs at character 2 ('é' vs 'o')")]
     fn is_reports_diff_pointer_for_multibyte_strings() {
         Assert::that(String::from("aée")).is("aoe");
     }
+
+    #[test]
+    fn is_diff_pointer_aligns_under_wide_characters() {
+        let result = std::panic::catch_unwind(|| {
+            Assert::that(String::from("日本語!")).is("日本語?");
+        });
+        let message = result.unwrap_err();
+        let message = message.downcast_ref::<String>().unwrap();
+        // One column for the opening quote, two for each CJK character.
+        let expected_pointer_line = format!(
+            "\n{}^ differs at character 4 ('!' vs '?')",
+            " ".repeat(13 + 7)
+        );
+        assert!(
+            message.contains(&expected_pointer_line),
+            "expected pointer line not found in: {message}"
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "^ differs at character 7 (U+00A0 (no-break space) vs '·' (space))\n  Diff:     `\"total:[-\\u{a0}-]{+·+}42\"`"
+    )]
+    fn is_spells_out_invisible_characters() {
+        Assert::that(String::from("total:\u{a0}42")).is("total: 42");
+    }
+
+    #[test]
+    fn is_truncates_huge_renderings_around_difference() {
+        let actual = "a".repeat(5000) + "X" + &"b".repeat(5000);
+        let expected = "a".repeat(5000) + "Y" + &"b".repeat(5000);
+        let result = std::panic::catch_unwind(|| {
+            crate::truncate::set_max_len(Some(30));
+            Assert::that(actual).is(expected);
+        });
+        let message = result.unwrap_err();
+        let message = message.downcast_ref::<String>().unwrap();
+        let window = format!(
+            "…[4991 chars]…{}X{}…[4982 chars]…",
+            "a".repeat(10),
+            "b".repeat(19)
+        );
+        assert!(
+            message.contains(&format!("\n  Actual:   `{window}`\n")),
+            "message: {message}"
+        );
+        // The caret sits under the `X`, after the elision marker.
+        let marker_width = "…[4991 chars]…".chars().count();
+        assert!(
+            message.contains(&format!(
+                "\n{}^ differs at character 5001 ('X' vs 'Y')",
+                " ".repeat(13 + marker_width + 10)
+            )),
+            "message: {message}"
+        );
+    }
+
+    #[test]
+    fn is_shows_full_output_when_truncation_is_off() {
+        let actual = "a".repeat(500) + "X";
+        let result = std::panic::catch_unwind(|| {
+            crate::truncate::set_max_len(None);
+            Assert::that(actual).is("a".repeat(500) + "Y");
+        });
+        let message = result.unwrap_err();
+        let message = message.downcast_ref::<String>().unwrap();
+        assert!(!message.contains("chars]"), "message: {message}");
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Order {
+        id: u32,
+        customer: &'static str,
+        lines: Vec<(&'static str, u32)>,
+        note: &'static str,
+    }
+
+    fn order(qty: u32, note: &'static str) -> Order {
+        Order {
+            id: 1,
+            customer: "ACME Corporation",
+            lines: vec![("apples", 3), ("pears", qty), ("plums", 7)],
+            note,
+        }
+    }
+
+    #[test]
+    fn is_reports_unified_diff_for_long_values() {
+        let result = std::panic::catch_unwind(|| {
+            Assert::that(order(2, "first")).is(order(5, "second"));
+        });
+        let message = result.unwrap_err();
+        let message = message.downcast_ref::<String>().unwrap();
+        assert!(
+            message.contains("\n  --- actual\n  +++ expected\n  @@ "),
+            "message: {message}"
+        );
+        assert!(
+            message.contains("\n  -            5,\n") || message.contains("\n  -            2,\n"),
+            "message: {message}"
+        );
+        assert!(
+            message.contains("\n  -    note: \"first\",\n  +    note: \"second\","),
+            "message: {message}"
+        );
+        assert!(!message.contains("differs at"), "message: {message}");
+    }
+
+    #[test]
+    fn is_diffs_multi_line_strings_line_by_line() {
+        let result = std::panic::catch_unwind(|| {
+            Assert::that(String::from("one\ntwo\nthree")).is("one\n2\nthree");
+        });
+        let message = result.unwrap_err();
+        let message = message.downcast_ref::<String>().unwrap();
+        assert!(
+            message.ends_with("\n  @@ -1,3 +1,3 @@\n   \"one\\n\n  -two\\n\n  +2\\n\n   three\""),
+            "message: {message}"
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Assertion failed: `!(actual > other)`\n  Actual:   `3`\n  Other:    `2`"
+    )]
+    fn not_is_gt_reports_full_message() {
+        Assert::that(3).not().is_gt(2);
+    }
+
+    #[test]
+    fn not_flips_comparisons() {
+        Assert::that(3)
+            .not()
+            .is(4)
+            .not()
+            .is_not(3)
+            .not()
+            .is_lt(3)
+            .not()
+            .satisfies(|v| v % 2 == 0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Assertion failed: `(actual < other)`\n  Actual:   `NaN`\n  Other:    `1.0`\n  \
+                    Reason:   `values are not comparable (partial_cmp returned None)`"
+    )]
+    fn is_lt_reports_incomparable_values() {
+        Assert::that(f64::NAN).is_lt(1.0);
+    }
+
+    #[test]
+    fn incomparable_values_fail_negated_comparisons_too() {
+        for check in [
+            |a: Assert<f64>| a.not().is_gt(f64::NAN),
+            |a: Assert<f64>| a.not().is_ge(f64::NAN),
+            |a: Assert<f64>| a.not().is_lt(f64::NAN),
+            |a: Assert<f64>| a.not().is_le(f64::NAN),
+        ] {
+            let error = Assert::check(1.0, check).err().unwrap();
+            assert_eq!(
+                error.details()[2].value(),
+                "values are not comparable (partial_cmp returned None)"
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Assertion failed: `!(actual.partial_cmp(other).is_some())`\n  \
+                    Actual:   `1`\n  Other:    `2`"
+    )]
+    fn not_is_comparable_to_reports_full_message() {
+        Assert::that(1).not().is_comparable_to(2);
+    }
+
+    #[test]
+    fn compares_as_reports_found_ordering() {
+        let error = Assert::check(f64::NAN, |a| a.compares_as(1.0, Ordering::Equal))
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.to_string(),
+            "Assertion failed: `(actual.partial_cmp(other) == expected)`\n  \
+             Actual:   `NaN`\n  \
+             Other:    `1.0`\n  \
+             Expected: `Equal`\n  \
+             Found:    `values are not comparable (partial_cmp returned None)`"
+        );
+        let error = Assert::check(1, |a| a.not().compares_as(1, Ordering::Equal))
+            .err()
+            .unwrap();
+        assert_eq!(error.expected(), Some("not Equal"));
+        Assert::that("a")
+            .compares_as("b", Ordering::Less)
+            .not()
+            .compares_as("b", Ordering::Greater);
+    }
+
+    #[test]
+    fn is_lists_differing_paths() {
+        let error = Assert::check(order(2, "first"), |a| a.is(order(5, "second")))
+            .err()
+            .unwrap();
+        assert!(
+            error.diff().unwrap().ends_with(
+                "\n  Differences:\n    .lines[1].1: 2 != 5\n    .note: \"first\" != \"second\""
+            ),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn is_lists_no_paths_for_scalars() {
+        let error = Assert::check(2, |a| a.is(3)).err().unwrap();
+        assert!(!error.to_string().contains("Differences"), "{error}");
+    }
+
+    #[test]
+    fn is_falls_back_to_text_diff_for_deeply_nested_values() {
+        #[derive(Debug, PartialEq)]
+        enum Nested {
+            Leaf(u32),
+            Node(Box<Nested>),
+        }
+        let nested = |leaf| (0..300).fold(Nested::Leaf(leaf), |n, _| Nested::Node(Box::new(n)));
+        let error = Assert::check(nested(1), |a| a.is(nested(2))).err().unwrap();
+        let diff = error.diff().unwrap();
+        assert!(!diff.contains("Differences"), "{diff}");
+        assert!(diff.starts_with("  --- actual\n  +++ expected\n"), "{diff}");
+    }
+
+    #[test]
+    fn is_deep_eq_reports_full_message() {
+        let error = Assert::check(vec![order(2, "x")], |a| a.is_deep_eq([order(3, "x")]))
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.header(),
+            "Assertion failed: `(actual.is_deep_eq(expected))`"
+        );
+        assert_eq!(
+            error.diff(),
+            Some("  Differences:\n    [0].lines[1].1: 2 != 3")
+        );
+    }
+
+    #[test]
+    fn is_deep_eq_compares_maps_and_sets_in_any_order() {
+        use std::collections::{HashMap, HashSet};
+        let map: HashMap<u32, Vec<u32>> = (0..20).map(|k| (k, vec![k])).collect();
+        let reversed: Vec<(u32, Vec<u32>)> = (0..20).rev().map(|k| (k, vec![k])).collect();
+        Assert::that(&map).is_deep_eq(HashMap::<u32, Vec<u32>>::from_iter(reversed));
+        Assert::that(HashSet::from([1, 2, 3])).is_deep_eq(HashSet::from([3, 2, 1]));
+    }
+
+    #[test]
+    fn is_deep_eq_works_without_partial_eq() {
+        #[derive(Debug)]
+        #[allow(dead_code)]
+        struct Opaque(u32);
+        Assert::that(Opaque(1))
+            .is_deep_eq(Opaque(1))
+            .not()
+            .is_deep_eq(Opaque(2));
+        let error = Assert::check(Opaque(1), |a| a.is_deep_eq(Opaque(2)))
+            .err()
+            .unwrap();
+        assert_eq!(error.diff(), Some("  Differences:\n    .0: 1 != 2"));
+    }
+
+    #[test]
+    fn is_deep_eq_falls_back_to_inline_diff() {
+        let error = Assert::check("a b", |a| a.is_deep_eq("a c")).err().unwrap();
+        assert_eq!(error.diff(), Some("  Diff:     `\"a [-b-]{+c+}\"`"));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Assertion failed: `!(actual.is_deep_eq(expected))`\n  Actual:   `[1]`\n  Expected: `not [1]`"
+    )]
+    fn not_is_deep_eq_reports_full_message() {
+        Assert::that(vec![1]).not().is_deep_eq([1]);
+    }
 }
@@ -0,0 +1,301 @@
+//! Structured description of a failed assertion, and the non-panicking
+//! [`Assert::check`] entry point that returns it as a value.
+
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe, Location};
+use std::sync::Arc;
+
+use crate::Assert;
+use crate::collector::{self, Collector};
+
+/// A failed assertion: what was asserted, on which (optionally labelled)
+/// value, the actual/expected renderings and any diff, and where the
+/// assertion was made.
+///
+/// Its [Display](fmt::Display) output is exactly the panic message the
+/// assertion would have produced.
+#[derive(Debug, Clone)]
+pub struct AssertionError {
+    label: Option<String>,
+    assertion: String,
+    details: Vec<Detail>,
+    diff: Option<String>,
+    negated: bool,
+    location: &'static Location<'static>,
+}
+
+/// One `Name: `value`` line of an [AssertionError], e.g. `Actual`,
+/// `Expected` or `Missing`.
+#[derive(Debug, Clone)]
+pub struct Detail {
+    name: String,
+    value: String,
+    /// Width the name is padded to, so values of related lines (e.g.
+    /// `Actual:` and `Expected:`) start in the same column.
+    width: usize,
+}
+
+impl Detail {
+    /// The name of this line, e.g. `"Actual"`.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The rendered value, without the surrounding backticks.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// The name with its colon, padded so the value lines up with those
+    /// of related lines, e.g. `"Actual:  "`.
+    pub(crate) fn padded_name(&self) -> String {
+        format!(
+            "{:<width$}",
+            format!("{}:", self.name),
+            width = self.width + 1
+        )
+    }
+}
+
+impl AssertionError {
+    pub(crate) fn new(
+        label: Option<String>,
+        assertion: &str,
+        location: &'static Location<'static>,
+    ) -> Self {
+        AssertionError {
+            label,
+            assertion: assertion.to_string(),
+            details: Vec::new(),
+            diff: None,
+            negated: false,
+            location,
+        }
+    }
+
+    /// Adds a `Name: `value`` line.
+    pub fn detail(self, name: &str, value: String) -> Self {
+        self.aligned_detail(name, value, name)
+    }
+
+    /// Adds a `Name: `value`` line whose value starts in the same column
+    /// as that of a line named `align_to`.
+    pub fn aligned_detail(mut self, name: &str, value: String, align_to: &str) -> Self {
+        self.details.push(Detail {
+            name: name.to_string(),
+            value,
+            width: name.len().max(align_to.len()),
+        });
+        self
+    }
+
+    /// Marks the assertion as negated with [`Assert::not`] if `negated`,
+    /// so it reads `!(actual.contains(expected))`.
+    pub fn negated(mut self, negated: bool) -> Self {
+        if negated {
+            self.assertion = format!("!({})", self.assertion);
+            self.negated = true;
+        }
+        self
+    }
+
+    /// Sets the diff text shown below the detail lines.
+    pub fn with_diff(mut self, diff: String) -> Self {
+        self.diff = Some(diff);
+        self
+    }
+
+    /// The label attached with [`Assert::named`], if any.
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
+    /// The assertion expression, e.g. `"actual == expected"`, or
+    /// `"!(actual.contains(expected))"` for one negated with
+    /// [`Assert::not`].
+    pub fn assertion(&self) -> &str {
+        &self.assertion
+    }
+
+    /// The first line of the failure message, naming the assertion and
+    /// its label.
+    pub fn header(&self) -> String {
+        let assertion = if self.negated {
+            self.assertion.clone()
+        } else {
+            format!("({})", self.assertion)
+        };
+        match &self.label {
+            Some(label) => format!("Assertion failed for `{label}`: `{assertion}`"),
+            None => format!("Assertion failed: `{assertion}`"),
+        }
+    }
+
+    /// The rendering of the actual value, if the assertion reports one.
+    pub fn actual(&self) -> Option<&str> {
+        self.detail_value("Actual")
+    }
+
+    /// The rendering of the expected value, if the assertion reports one.
+    pub fn expected(&self) -> Option<&str> {
+        self.detail_value("Expected")
+    }
+
+    /// All `Name: `value`` lines of the failure, in order.
+    pub fn details(&self) -> &[Detail] {
+        &self.details
+    }
+
+    /// The diff text below the detail lines, e.g. the pointer to the
+    /// first difference for [`Assert::is`].
+    pub fn diff(&self) -> Option<&str> {
+        self.diff.as_deref()
+    }
+
+    /// Where the assertion was made.
+    pub fn location(&self) -> &'static Location<'static> {
+        self.location
+    }
+
+    fn detail_value(&self, name: &str) -> Option<&str> {
+        self.details
+            .iter()
+            .find(|detail| detail.name == name)
+            .map(Detail::value)
+    }
+}
+
+impl fmt::Display for AssertionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.header())?;
+        for detail in &self.details {
+            write!(f, "\n  {} `{}`", detail.padded_name(), detail.value)?;
+        }
+        if let Some(diff) = &self.diff {
+            write!(f, "\n{diff}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for AssertionError {}
+
+impl<T> Assert<T> {
+    /// Run the assertions in `f` on `actual` without panicking: returns
+    /// the [Assert] that `f` hands back, or the first failed assertion as
+    /// an [AssertionError].
+    ///
+    /// ```
+    /// # use assert4rs::Assert;
+    /// let checked = Assert::check(vec![1, 2, 3], |a| a.contains(&2).has_length(3));
+    /// assert!(checked.is_ok());
+    ///
+    /// let error = Assert::check(25, |a| a.named("user.age").is(30)).err().unwrap();
+    /// assert_eq!(error.label(), Some("user.age"));
+    /// assert_eq!(error.assertion(), "actual == expected");
+    /// assert_eq!(error.actual(), Some("25"));
+    /// assert_eq!(error.expected(), Some("30"));
+    /// ```
+    ///
+    /// The returned [Assert] panics on failure again, as usual. Stopping
+    /// at the first failure relies on unwinding, so this doesn't work with
+    /// `panic = "abort"`.
+    pub fn check<R>(
+        actual: T,
+        f: impl FnOnce(Assert<T>) -> Assert<R>,
+    ) -> Result<Assert<R>, AssertionError> {
+        let collector = Arc::new(Collector::first_failure());
+        let checked = Assert {
+            actual,
+            label: None,
+            collector: Some(collector.clone()),
+            negated: false,
+        };
+        match panic::catch_unwind(AssertUnwindSafe(|| {
+            collector::in_scope(&collector, || f(checked))
+        })) {
+            Ok(assert) => Ok(Assert {
+                collector: None,
+                ..assert
+            }),
+            Err(payload) if collector::is_abort(&*payload) => Err(collector.take().remove(0)),
+            Err(payload) => panic::resume_unwind(payload),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Assert;
+    use std::collections::HashMap;
+
+    #[test]
+    fn display_matches_panic_message() {
+        let error = Assert::check(1, |a| a.named("x").is(2)).err().unwrap();
+        let panic = std::panic::catch_unwind(|| {
+            Assert::that(1).named("x").is(2);
+        })
+        .unwrap_err();
+        assert_eq!(&error.to_string(), panic.downcast_ref::<String>().unwrap());
+    }
+
+    #[test]
+    fn exposes_structured_parts() {
+        let error = Assert::check(1, |a| a.is(2)).err().unwrap();
+        assert_eq!(error.label(), None);
+        assert_eq!(error.assertion(), "actual == expected");
+        assert_eq!(error.header(), "Assertion failed: `(actual == expected)`");
+        assert_eq!(error.actual(), Some("1"));
+        assert_eq!(error.expected(), Some("2"));
+        assert_eq!(
+            error.diff(),
+            Some("             ^ differs at character 0 ('1' vs '2')")
+        );
+    }
+
+    #[test]
+    fn reports_caller_location() {
+        let line = line!() + 1;
+        let error = Assert::check(1, |a| a.is(2)).err().unwrap();
+        assert_eq!(error.location().file(), file!());
+        assert_eq!(error.location().line(), line);
+    }
+
+    #[test]
+    fn stops_at_first_failure() {
+        let error = Assert::check(1, |a| a.is_gt(5).is(2)).err().unwrap();
+        assert_eq!(error.assertion(), "actual > other");
+    }
+
+    #[test]
+    fn exposes_structural_diff_details() {
+        let actual = HashMap::from([("a", 1), ("b", 2), ("d", 4)]);
+        let expected = HashMap::from([("a", 1), ("b", 99), ("c", 3)]);
+        let error = Assert::check(actual, |a| a.is_eq_to(expected))
+            .err()
+            .unwrap();
+        let names: Vec<&str> = error.details().iter().map(|d| d.name()).collect();
+        assert_eq!(names, ["Actual", "Expected"]);
+        assert_eq!(
+            error.diff(),
+            Some(
+                "  Differences:\n    [\"b\"]: 2 != 99\n    [\"c\"]: missing 3\n    [\"d\"]: extra 4"
+            )
+        );
+    }
+
+    #[test]
+    fn failed_unwrap_is_returned_as_error() {
+        let error = Assert::check(None::<i32>, |a| a.unwrap().is(1))
+            .err()
+            .unwrap();
+        assert_eq!(error.assertion(), "actual.is_some()");
+        assert_eq!(error.actual(), Some("None"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Assertion failed: `(actual == expected)`")]
+    fn returned_assert_panics_again() {
+        Assert::check(1, |a| a.is(1)).ok().unwrap().is(2);
+    }
+}
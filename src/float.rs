@@ -0,0 +1,334 @@
+//! Assertions on floats: closeness within an absolute, relative or ULP
+//! tolerance, and NaN, infinity and sign checks.
+
+use crate::Assert;
+
+/// The float assertions, for `f32` and `f64`, with `$bits` the signed
+/// integer type of the same width, for counting ULPs.
+macro_rules! float_assertions {
+    ($($float:ty, $bits:ty);* $(;)?) => {
+        $(
+            impl Assert<$float> {
+                /// Assert that `self` differs from `expected` by at most
+                /// `tolerance`.
+                ///
+                /// ```
+                /// # use assert4rs::Assert;
+                #[doc = concat!("Assert::that(0.1_", stringify!($float), " + 0.2).is_close_to(0.3, 1e-6);")]
+                /// ```
+                ///
+                /// ```should_panic
+                /// # use assert4rs::Assert;
+                /// // Assertion failed: `(actual.is_close_to(expected))`
+                /// //   Actual:    `1.5`
+                /// //   Expected:  `1.0`
+                /// //   Delta:     `0.5`
+                /// //   Tolerance: `0.1`
+                #[doc = concat!("Assert::that(1.5_", stringify!($float), ").is_close_to(1.0, 0.1);")]
+                /// ```
+                #[track_caller]
+                pub fn is_close_to(mut self, expected: $float, tolerance: $float) -> Self {
+                    let negated = self.take_negation();
+                    let delta = (self.actual - expected).abs();
+                    let close = self.actual == expected || delta <= tolerance;
+                    if close == negated {
+                        self.fail(self.closeness_error(
+                            "actual.is_close_to(expected)",
+                            expected,
+                            format!("{delta:?}"),
+                            format!("{tolerance:?}"),
+                            negated,
+                        ));
+                    }
+                    self
+                }
+
+                /// Assert that `self` differs from `expected` by at most
+                /// `tolerance` times the larger of the two, e.g. `1e-6` for
+                /// a millionth.
+                ///
+                /// ```
+                /// # use assert4rs::Assert;
+                #[doc = concat!("Assert::that(1_000_000.5_", stringify!($float), ").is_close_to_rel(1_000_000.0, 1e-6);")]
+                /// ```
+                ///
+                /// ```should_panic
+                /// # use assert4rs::Assert;
+                /// // Assertion failed: `(actual.is_close_to_rel(expected))`
+                /// //   Actual:    `2.0`
+                /// //   Expected:  `1.5`
+                /// //   Delta:     `0.5 (0.25 relative)`
+                /// //   Tolerance: `0.1 relative`
+                #[doc = concat!("Assert::that(2.0_", stringify!($float), ").is_close_to_rel(1.5, 0.1);")]
+                /// ```
+                #[track_caller]
+                pub fn is_close_to_rel(mut self, expected: $float, tolerance: $float) -> Self {
+                    let negated = self.take_negation();
+                    let delta = (self.actual - expected).abs();
+                    let relative = delta / self.actual.abs().max(expected.abs());
+                    let close = self.actual == expected || relative <= tolerance;
+                    if close == negated {
+                        self.fail(self.closeness_error(
+                            "actual.is_close_to_rel(expected)",
+                            expected,
+                            format!("{delta:?} ({relative:?} relative)"),
+                            format!("{tolerance:?} relative"),
+                            negated,
+                        ));
+                    }
+                    self
+                }
+
+                /// Assert that `self` is at most `ulps` representable
+                /// floats away from `expected`. Zeroes of either sign are
+                /// no ULPs apart, and NaN is never close to anything.
+                ///
+                /// ```
+                /// # use assert4rs::Assert;
+                #[doc = concat!("Assert::that(0.1_", stringify!($float), " + 0.2).is_within_ulps(0.3, 1);")]
+                /// ```
+                ///
+                /// ```should_panic
+                /// # use assert4rs::Assert;
+                #[doc = concat!("Assert::that(1.0_", stringify!($float), ".next_up().next_up()).is_within_ulps(1.0, 1);")]
+                /// ```
+                #[track_caller]
+                pub fn is_within_ulps(mut self, expected: $float, ulps: u32) -> Self {
+                    // Orders the bit patterns of floats like the floats
+                    // themselves, so that adjacent floats are adjacent
+                    // integers and both zeroes are 0.
+                    let ordered = |value: $float| {
+                        let bits = value.to_bits() as $bits;
+                        if bits < 0 { <$bits>::MIN - bits } else { bits }
+                    };
+                    let negated = self.take_negation();
+                    let apart = (i128::from(ordered(self.actual)) - i128::from(ordered(expected)))
+                        .unsigned_abs();
+                    let close = !self.actual.is_nan() && !expected.is_nan() && apart <= u128::from(ulps);
+                    if close == negated {
+                        let delta = if self.actual.is_nan() || expected.is_nan() {
+                            String::from("NaN")
+                        } else {
+                            ulp_count(apart)
+                        };
+                        self.fail(self.closeness_error(
+                            "actual.is_within_ulps(expected)",
+                            expected,
+                            delta,
+                            ulp_count(u128::from(ulps)),
+                            negated,
+                        ));
+                    }
+                    self
+                }
+
+                /// Assert that `self` is NaN.
+                ///
+                /// ```
+                /// # use assert4rs::Assert;
+                #[doc = concat!("Assert::that(", stringify!($float), "::NAN).is_nan();")]
+                /// ```
+                ///
+                /// ```should_panic
+                /// # use assert4rs::Assert;
+                #[doc = concat!("Assert::that(1.0_", stringify!($float), ").is_nan();")]
+                /// ```
+                #[track_caller]
+                pub fn is_nan(self) -> Self {
+                    self.has_property("actual.is_nan()", <$float>::is_nan)
+                }
+
+                /// Assert that `self` is neither infinite nor NaN.
+                ///
+                /// ```
+                /// # use assert4rs::Assert;
+                #[doc = concat!("Assert::that(1.0_", stringify!($float), ").is_finite();")]
+                /// ```
+                ///
+                /// ```should_panic
+                /// # use assert4rs::Assert;
+                #[doc = concat!("Assert::that(", stringify!($float), "::INFINITY).is_finite();")]
+                /// ```
+                #[track_caller]
+                pub fn is_finite(self) -> Self {
+                    self.has_property("actual.is_finite()", <$float>::is_finite)
+                }
+
+                /// Assert that `self` is positive or negative infinity.
+                ///
+                /// ```
+                /// # use assert4rs::Assert;
+                #[doc = concat!("Assert::that(", stringify!($float), "::NEG_INFINITY).is_infinite();")]
+                /// ```
+                ///
+                /// ```should_panic
+                /// # use assert4rs::Assert;
+                #[doc = concat!("Assert::that(", stringify!($float), "::NAN).is_infinite();")]
+                /// ```
+                #[track_caller]
+                pub fn is_infinite(self) -> Self {
+                    self.has_property("actual.is_infinite()", <$float>::is_infinite)
+                }
+
+                /// Assert that `self` has its sign bit set, as negative
+                /// numbers, `-0.0` and some NaNs do.
+                ///
+                /// ```
+                /// # use assert4rs::Assert;
+                #[doc = concat!("Assert::that(-0.0_", stringify!($float), ").is_sign_negative();")]
+                /// ```
+                ///
+                /// ```should_panic
+                /// # use assert4rs::Assert;
+                #[doc = concat!("Assert::that(0.0_", stringify!($float), ").is_sign_negative();")]
+                /// ```
+                #[track_caller]
+                pub fn is_sign_negative(self) -> Self {
+                    self.has_property("actual.is_sign_negative()", <$float>::is_sign_negative)
+                }
+
+                /// The error for a failed closeness `assertion`, showing by
+                /// how much `self` and `expected` differ and by how much
+                /// they were allowed to.
+                #[track_caller]
+                fn closeness_error(
+                    &self,
+                    assertion: &str,
+                    expected: $float,
+                    delta: String,
+                    tolerance: String,
+                    negated: bool,
+                ) -> crate::AssertionError {
+                    let expected = match negated {
+                        true => format!("not {expected:?}"),
+                        false => format!("{expected:?}"),
+                    };
+                    self.error(assertion)
+                        .negated(negated)
+                        .aligned_detail("Actual", format!("{:?}", self.actual), "Tolerance")
+                        .aligned_detail("Expected", expected, "Tolerance")
+                        .aligned_detail("Delta", delta, "Tolerance")
+                        .detail("Tolerance", tolerance)
+                }
+            }
+        )*
+    };
+}
+
+float_assertions!(f32, i32; f64, i64);
+
+/// Renders e.g. `1 ULP` or `3 ULPs`.
+fn ulp_count(ulps: u128) -> String {
+    match ulps {
+        1 => String::from("1 ULP"),
+        ulps => format!("{ulps} ULPs"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Assert;
+
+    #[test]
+    fn is_close_to_reports_delta_and_tolerance() {
+        let error = Assert::check(1.5_f64, |a| a.is_close_to(1.0, 0.1))
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.to_string(),
+            "Assertion failed: `(actual.is_close_to(expected))`\n  \
+             Actual:    `1.5`\n  \
+             Expected:  `1.0`\n  \
+             Delta:     `0.5`\n  \
+             Tolerance: `0.1`"
+        );
+    }
+
+    #[test]
+    fn is_close_to_rejects_nan() {
+        let error = Assert::check(f32::NAN, |a| a.is_close_to(1.0, f32::INFINITY))
+            .err()
+            .unwrap();
+        assert_eq!(error.details()[2].value(), "NaN");
+    }
+
+    #[test]
+    fn not_is_close_to_reads_not() {
+        let error = Assert::check(1.0_f32, |a| a.not().is_close_to(1.05, 0.1))
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.header(),
+            "Assertion failed: `!(actual.is_close_to(expected))`"
+        );
+        assert_eq!(error.expected(), Some("not 1.05"));
+    }
+
+    #[test]
+    fn is_close_to_rel_is_relative_to_the_larger_value() {
+        Assert::that(100.0_f64).is_close_to_rel(110.0, 0.1);
+        let error = Assert::check(100.0_f64, |a| a.is_close_to_rel(112.5, 0.1))
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.details()[2].value(),
+            "12.5 (0.1111111111111111 relative)"
+        );
+        assert_eq!(error.details()[3].value(), "0.1 relative");
+    }
+
+    #[test]
+    fn is_close_to_rel_accepts_equal_zeroes() {
+        Assert::that(0.0_f64).is_close_to_rel(-0.0, 0.0);
+    }
+
+    #[test]
+    fn equal_infinities_are_close() {
+        Assert::that(f64::INFINITY)
+            .is_close_to(f64::INFINITY, 0.1)
+            .is_close_to_rel(f64::INFINITY, 0.1)
+            .not()
+            .is_close_to(f64::NEG_INFINITY, f64::MAX)
+            .not()
+            .is_close_to(f64::MAX, 0.1);
+        Assert::that(f32::NEG_INFINITY).is_close_to(f32::NEG_INFINITY, 0.0);
+    }
+
+    #[test]
+    fn is_within_ulps_counts_representable_floats() {
+        let next = f32::from_bits(1.0_f32.to_bits() + 2);
+        Assert::that(next).is_within_ulps(1.0, 2);
+        Assert::that(-0.0_f64).is_within_ulps(0.0, 0);
+        Assert::that(f64::from_bits(1)).is_within_ulps(-f64::from_bits(1), 2);
+        let error = Assert::check(next, |a| a.is_within_ulps(1.0, 1))
+            .err()
+            .unwrap();
+        assert_eq!(error.details()[2].value(), "2 ULPs");
+        assert_eq!(error.details()[3].value(), "1 ULP");
+    }
+
+    #[test]
+    fn is_within_ulps_rejects_nan() {
+        let error = Assert::check(f64::NAN, |a| a.is_within_ulps(f64::NAN, u32::MAX))
+            .err()
+            .unwrap();
+        assert_eq!(error.details()[2].value(), "NaN");
+    }
+
+    #[test]
+    fn properties_report_actual() {
+        let error = Assert::check(1.0_f64, |a| a.is_nan()).err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "Assertion failed: `(actual.is_nan())`\n  Actual: `1.0`"
+        );
+        Assert::that(f32::NAN).not().is_finite().not().is_infinite();
+        Assert::that(-f64::NAN).is_sign_negative();
+    }
+
+    #[test]
+    fn not_is_nan_reads_not() {
+        let error = Assert::check(f64::NAN, |a| a.not().is_nan()).err().unwrap();
+        assert_eq!(error.header(), "Assertion failed: `!(actual.is_nan())`");
+    }
+}
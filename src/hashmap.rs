@@ -19,25 +19,36 @@ impl<K, V> Assert<HashMap<K, V>> {
     /// Assert::that(HashMap::from([("a", 1)])).contains_key(&"z");
     /// ```
     #[track_caller]
-    pub fn contains_key(self, key: &K) -> Self
+    pub fn contains_key(mut self, key: &K) -> Self
     where
         K: Eq + Hash + Debug,
         V: Debug,
     {
-        assert!(
-            self.actual.contains_key(key),
-            "{}\n  Actual:   `{:?}`\n  Expected to contain key: `{:?}`\n  Missing key: `{:?}`",
-            self.header("actual.contains_key(key)"),
-            self.actual,
-            key,
-            key,
-        );
+        let negated = self.take_negation();
+        if self.actual.contains_key(key) == negated {
+            let error = self
+                .error("actual.contains_key(key)")
+                .negated(negated)
+                .aligned_detail(
+                    "Actual",
+                    crate::truncate::map(self.actual.iter()),
+                    "Expected",
+                );
+            self.fail(if negated {
+                error.detail("Expected not to contain key", format!("{key:?}"))
+            } else {
+                error
+                    .detail("Expected to contain key", format!("{key:?}"))
+                    .detail("Missing key", format!("{key:?}"))
+            });
+        }
         self
     }
 
     /// Returns an [Assert] for the value at `key`, or `None` if absent.
     /// Consumes the map entry (via [HashMap::remove]) so this works
     /// without requiring `V: Clone`, matching `vec.rs`'s `get` pattern.
+    /// Appends `[key]` to the label path.
     ///
     /// ```
     /// # use assert4rs::Assert;
@@ -45,11 +56,11 @@ impl<K, V> Assert<HashMap<K, V>> {
     /// Assert::that(HashMap::from([("a", 1)])).get(&"a").unwrap().is(1);
     /// Assert::that(HashMap::from([("a", 1)])).get(&"z").is_none();
     /// ```
-    pub fn get(mut self, key: &K) -> Assert<Option<V>>
+    pub fn get(self, key: &K) -> Assert<Option<V>>
     where
-        K: Eq + Hash,
+        K: Eq + Hash + Debug,
     {
-        Assert::that(self.actual.remove(key))
+        self.map_suffixed(&format!("[{key:?}]"), |mut actual| actual.remove(key))
     }
 
     /// Assert that the actual map is empty.
@@ -66,17 +77,19 @@ impl<K, V> Assert<HashMap<K, V>> {
     /// Assert::that(HashMap::from([("a", 1)])).is_empty();
     /// ```
     #[track_caller]
-    pub fn is_empty(self) -> Self
+    pub fn is_empty(mut self) -> Self
     where
         K: Debug,
         V: Debug,
     {
-        assert!(
-            self.actual.is_empty(),
-            "{}\n  Actual: `{:?}`",
-            self.header("actual.is_empty()"),
-            self.actual,
-        );
+        let negated = self.take_negation();
+        if self.actual.is_empty() == negated {
+            self.fail(
+                self.error("actual.is_empty()")
+                    .negated(negated)
+                    .detail("Actual", crate::truncate::map(self.actual.iter())),
+            );
+        }
         self
     }
 
@@ -94,14 +107,21 @@ impl<K, V> Assert<HashMap<K, V>> {
     /// Assert::that(HashMap::from([("a", 1)])).has_length(2);
     /// ```
     #[track_caller]
-    pub fn has_length(self, expected: usize) -> Self {
-        assert!(
-            self.actual.len() == expected,
-            "{}\n  Actual:   `{}`\n  Expected: `{}`",
-            self.header("actual.len() == expected"),
-            self.actual.len(),
-            expected,
-        );
+    pub fn has_length(mut self, expected: usize) -> Self {
+        let negated = self.take_negation();
+        if (self.actual.len() == expected) == negated {
+            let expected = if negated {
+                format!("not {expected}")
+            } else {
+                expected.to_string()
+            };
+            self.fail(
+                self.error("actual.len() == expected")
+                    .negated(negated)
+                    .aligned_detail("Actual", self.actual.len().to_string(), "Expected")
+                    .detail("Expected", expected),
+            );
+        }
         self
     }
 }
@@ -128,19 +148,29 @@ impl<K, V> Assert<&HashMap<K, V>> {
     /// Assert::that(&m).contains_key(&"z");
     /// ```
     #[track_caller]
-    pub fn contains_key(self, key: &K) -> Self
+    pub fn contains_key(mut self, key: &K) -> Self
     where
         K: Eq + Hash + Debug,
         V: Debug,
     {
-        assert!(
-            self.actual.contains_key(key),
-            "{}\n  Actual:   `{:?}`\n  Expected to contain key: `{:?}`\n  Missing key: `{:?}`",
-            self.header("actual.contains_key(key)"),
-            self.actual,
-            key,
-            key,
-        );
+        let negated = self.take_negation();
+        if self.actual.contains_key(key) == negated {
+            let error = self
+                .error("actual.contains_key(key)")
+                .negated(negated)
+                .aligned_detail(
+                    "Actual",
+                    crate::truncate::map(self.actual.iter()),
+                    "Expected",
+                );
+            self.fail(if negated {
+                error.detail("Expected not to contain key", format!("{key:?}"))
+            } else {
+                error
+                    .detail("Expected to contain key", format!("{key:?}"))
+                    .detail("Missing key", format!("{key:?}"))
+            });
+        }
         self
     }
 
@@ -160,17 +190,19 @@ impl<K, V> Assert<&HashMap<K, V>> {
     /// Assert::that(&m).is_empty();
     /// ```
     #[track_caller]
-    pub fn is_empty(self) -> Self
+    pub fn is_empty(mut self) -> Self
     where
         K: Debug,
         V: Debug,
     {
-        assert!(
-            self.actual.is_empty(),
-            "{}\n  Actual: `{:?}`",
-            self.header("actual.is_empty()"),
-            self.actual,
-        );
+        let negated = self.take_negation();
+        if self.actual.is_empty() == negated {
+            self.fail(
+                self.error("actual.is_empty()")
+                    .negated(negated)
+                    .detail("Actual", crate::truncate::map(self.actual.iter())),
+            );
+        }
         self
     }
 
@@ -190,70 +222,22 @@ impl<K, V> Assert<&HashMap<K, V>> {
     /// Assert::that(&m).has_length(2);
     /// ```
     #[track_caller]
-    pub fn has_length(self, expected: usize) -> Self {
-        assert!(
-            self.actual.len() == expected,
-            "{}\n  Actual:   `{}`\n  Expected: `{}`",
-            self.header("actual.len() == expected"),
-            self.actual.len(),
-            expected,
-        );
-        self
-    }
-}
-
-/// Structural-diff equality check. Requires `K: Ord` and `V: PartialEq`
-/// (beyond what `contains_key`/`get`/`is_empty`/`has_length` need) so
-/// the diff's Missing/Extra/Changed lists can be sorted for
-/// deterministic output and values can be compared for the `Changed`
-/// category.
-impl<K, V> Assert<HashMap<K, V>>
-where
-    K: Eq + Hash + Debug + Ord,
-    V: PartialEq + Debug,
-{
-    /// Assert that `self` equals `expected`, reporting a structural
-    /// (entry-aware) diff on failure instead of a text diff.
-    ///
-    /// ```
-    /// # use assert4rs::Assert;
-    /// # use std::collections::HashMap;
-    /// Assert::that(HashMap::from([("a", 1)])).is_eq_to(HashMap::from([("a", 1)]));
-    /// ```
-    ///
-    /// ```should_panic
-    /// # use assert4rs::Assert;
-    /// # use std::collections::HashMap;
-    /// Assert::that(HashMap::from([("a", 1)])).is_eq_to(HashMap::from([("a", 2)]));
-    /// ```
-    #[track_caller]
-    pub fn is_eq_to(self, expected: HashMap<K, V>) -> Self {
-        if self.actual == expected {
-            return self;
-        }
-        let (missing_keys, extra_keys, changed) =
-            crate::structural_diff::map_diff(&self.actual, &expected);
-        let mut message = format!(
-            "{}\n  Actual:   `{:?}`\n  Expected: `{:?}`",
-            self.header("actual.is_eq_to(expected)"),
-            self.actual,
-            expected,
-        );
-        if !missing_keys.is_empty() {
-            message.push_str(&format!("\n  Missing keys: `{missing_keys:?}`"));
-        }
-        if !extra_keys.is_empty() {
-            message.push_str(&format!("\n  Extra keys: `{extra_keys:?}`"));
+    pub fn has_length(mut self, expected: usize) -> Self {
+        let negated = self.take_negation();
+        if (self.actual.len() == expected) == negated {
+            let expected = if negated {
+                format!("not {expected}")
+            } else {
+                expected.to_string()
+            };
+            self.fail(
+                self.error("actual.len() == expected")
+                    .negated(negated)
+                    .aligned_detail("Actual", self.actual.len().to_string(), "Expected")
+                    .detail("Expected", expected),
+            );
         }
-        if !changed.is_empty() {
-            let rendered: Vec<String> = changed
-                .iter()
-                .map(|(k, av, ev)| format!("{k:?}: {av:?} != {ev:?}"))
-                .collect();
-            let changed_repr = format!("{{{}}}", rendered.join(", "));
-            message.push_str(&format!("\n  Changed: `{changed_repr}`"));
-        }
-        panic!("{message}");
+        self
     }
 }
 
@@ -332,15 +316,9 @@ mod tests {
         let message = result.unwrap_err();
         let message = message.downcast_ref::<String>().unwrap();
         assert!(
-            message.contains("Missing keys: `[\"c\"]`"),
-            "message: {message}"
-        );
-        assert!(
-            message.contains("Extra keys: `[\"d\"]`"),
-            "message: {message}"
-        );
-        assert!(
-            message.contains("Changed: `{\"b\": 2 != 99}`"),
+            message.ends_with(
+                "\n  Differences:\n    [\"b\"]: 2 != 99\n    [\"c\"]: missing 3\n    [\"d\"]: extra 4"
+            ),
             "message: {message}"
         );
     }
@@ -381,4 +359,21 @@ mod tests {
         let m = HashMap::from([("a", 1)]);
         Assert::that(&m).has_length(5);
     }
+
+    #[test]
+    #[should_panic(
+        expected = "Assertion failed: `!(actual.contains_key(key))`\n  Actual:   `{\"a\": 1}`\n  Expected not to contain key: `\"a\"`"
+    )]
+    fn not_contains_key_reports_full_message() {
+        Assert::that(HashMap::from([("a", 1)]))
+            .not()
+            .contains_key(&"a");
+    }
+
+    #[test]
+    fn not_flips_map_assertions() {
+        let m = HashMap::from([("a", 1)]);
+        Assert::that(&m).not().is_empty().not().has_length(2);
+        Assert::that(m).not().is_eq_to(HashMap::from([("a", 2)]));
+    }
 }
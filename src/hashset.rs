@@ -19,18 +19,28 @@ impl<T> Assert<HashSet<T>> {
     /// Assert::that(HashSet::from([1, 2, 3])).contains(&9);
     /// ```
     #[track_caller]
-    pub fn contains(self, expected: &T) -> Self
+    pub fn contains(mut self, expected: &T) -> Self
     where
         T: Eq + Hash + Debug,
     {
-        assert!(
-            self.actual.contains(expected),
-            "{}\n  Actual:   `{:?}`\n  Expected to contain: `{:?}`\n  Missing: `{:?}`",
-            self.header("actual.contains(expected)"),
-            self.actual,
-            expected,
-            expected,
-        );
+        let negated = self.take_negation();
+        if self.actual.contains(expected) == negated {
+            let error = self
+                .error("actual.contains(expected)")
+                .negated(negated)
+                .aligned_detail(
+                    "Actual",
+                    crate::truncate::set(self.actual.iter()),
+                    "Expected",
+                );
+            self.fail(if negated {
+                error.detail("Expected not to contain", format!("{expected:?}"))
+            } else {
+                error
+                    .detail("Expected to contain", format!("{expected:?}"))
+                    .detail("Missing", format!("{expected:?}"))
+            });
+        }
         self
     }
 
@@ -48,16 +58,18 @@ impl<T> Assert<HashSet<T>> {
     /// Assert::that(HashSet::from([1])).is_empty();
     /// ```
     #[track_caller]
-    pub fn is_empty(self) -> Self
+    pub fn is_empty(mut self) -> Self
     where
         T: Debug,
     {
-        assert!(
-            self.actual.is_empty(),
-            "{}\n  Actual: `{:?}`",
-            self.header("actual.is_empty()"),
-            self.actual,
-        );
+        let negated = self.take_negation();
+        if self.actual.is_empty() == negated {
+            self.fail(
+                self.error("actual.is_empty()")
+                    .negated(negated)
+                    .detail("Actual", crate::truncate::set(self.actual.iter())),
+            );
+        }
         self
     }
 
@@ -75,14 +87,21 @@ impl<T> Assert<HashSet<T>> {
     /// Assert::that(HashSet::from([1, 2, 3])).has_length(2);
     /// ```
     #[track_caller]
-    pub fn has_length(self, expected: usize) -> Self {
-        assert!(
-            self.actual.len() == expected,
-            "{}\n  Actual:   `{}`\n  Expected: `{}`",
-            self.header("actual.len() == expected"),
-            self.actual.len(),
-            expected,
-        );
+    pub fn has_length(mut self, expected: usize) -> Self {
+        let negated = self.take_negation();
+        if (self.actual.len() == expected) == negated {
+            let expected = if negated {
+                format!("not {expected}")
+            } else {
+                expected.to_string()
+            };
+            self.fail(
+                self.error("actual.len() == expected")
+                    .negated(negated)
+                    .aligned_detail("Actual", self.actual.len().to_string(), "Expected")
+                    .detail("Expected", expected),
+            );
+        }
         self
     }
 }
@@ -107,18 +126,28 @@ impl<T> Assert<&HashSet<T>> {
     /// Assert::that(&s).contains(&9);
     /// ```
     #[track_caller]
-    pub fn contains(self, expected: &T) -> Self
+    pub fn contains(mut self, expected: &T) -> Self
     where
         T: Eq + Hash + Debug,
     {
-        assert!(
-            self.actual.contains(expected),
-            "{}\n  Actual:   `{:?}`\n  Expected to contain: `{:?}`\n  Missing: `{:?}`",
-            self.header("actual.contains(expected)"),
-            self.actual,
-            expected,
-            expected,
-        );
+        let negated = self.take_negation();
+        if self.actual.contains(expected) == negated {
+            let error = self
+                .error("actual.contains(expected)")
+                .negated(negated)
+                .aligned_detail(
+                    "Actual",
+                    crate::truncate::set(self.actual.iter()),
+                    "Expected",
+                );
+            self.fail(if negated {
+                error.detail("Expected not to contain", format!("{expected:?}"))
+            } else {
+                error
+                    .detail("Expected to contain", format!("{expected:?}"))
+                    .detail("Missing", format!("{expected:?}"))
+            });
+        }
         self
     }
 
@@ -138,16 +167,18 @@ impl<T> Assert<&HashSet<T>> {
     /// Assert::that(&s).is_empty();
     /// ```
     #[track_caller]
-    pub fn is_empty(self) -> Self
+    pub fn is_empty(mut self) -> Self
     where
         T: Debug,
     {
-        assert!(
-            self.actual.is_empty(),
-            "{}\n  Actual: `{:?}`",
-            self.header("actual.is_empty()"),
-            self.actual,
-        );
+        let negated = self.take_negation();
+        if self.actual.is_empty() == negated {
+            self.fail(
+                self.error("actual.is_empty()")
+                    .negated(negated)
+                    .detail("Actual", crate::truncate::set(self.actual.iter())),
+            );
+        }
         self
     }
 
@@ -167,58 +198,22 @@ impl<T> Assert<&HashSet<T>> {
     /// Assert::that(&s).has_length(2);
     /// ```
     #[track_caller]
-    pub fn has_length(self, expected: usize) -> Self {
-        assert!(
-            self.actual.len() == expected,
-            "{}\n  Actual:   `{}`\n  Expected: `{}`",
-            self.header("actual.len() == expected"),
-            self.actual.len(),
-            expected,
-        );
-        self
-    }
-}
-
-/// Structural-diff equality check. Requires `T: Ord` (beyond what
-/// `contains`/`is_empty`/`has_length` need) so the diff's Extra/Missing
-/// lists can be sorted for deterministic output.
-impl<T> Assert<HashSet<T>>
-where
-    T: Eq + Hash + Debug + Ord,
-{
-    /// Assert that `self` equals `expected`, reporting a structural
-    /// (element-aware) diff on failure instead of a text diff.
-    ///
-    /// ```
-    /// # use assert4rs::Assert;
-    /// # use std::collections::HashSet;
-    /// Assert::that(HashSet::from([1, 2, 3])).is_eq_to(HashSet::from([1, 2, 3]));
-    /// ```
-    ///
-    /// ```should_panic
-    /// # use assert4rs::Assert;
-    /// # use std::collections::HashSet;
-    /// Assert::that(HashSet::from([1, 2, 3])).is_eq_to(HashSet::from([1, 2, 4]));
-    /// ```
-    #[track_caller]
-    pub fn is_eq_to(self, expected: HashSet<T>) -> Self {
-        if self.actual == expected {
-            return self;
-        }
-        let (extra, missing) = crate::structural_diff::set_diff(&self.actual, &expected);
-        let mut message = format!(
-            "{}\n  Actual:   `{:?}`\n  Expected: `{:?}`",
-            self.header("actual.is_eq_to(expected)"),
-            self.actual,
-            expected,
-        );
-        if !extra.is_empty() {
-            message.push_str(&format!("\n  Extra:    `{extra:?}`"));
-        }
-        if !missing.is_empty() {
-            message.push_str(&format!("\n  Missing:  `{missing:?}`"));
+    pub fn has_length(mut self, expected: usize) -> Self {
+        let negated = self.take_negation();
+        if (self.actual.len() == expected) == negated {
+            let expected = if negated {
+                format!("not {expected}")
+            } else {
+                expected.to_string()
+            };
+            self.fail(
+                self.error("actual.len() == expected")
+                    .negated(negated)
+                    .aligned_detail("Actual", self.actual.len().to_string(), "Expected")
+                    .detail("Expected", expected),
+            );
         }
-        panic!("{message}");
+        self
     }
 }
 
@@ -288,8 +283,10 @@ mod tests {
         });
         let message = result.unwrap_err();
         let message = message.downcast_ref::<String>().unwrap();
-        assert!(message.contains("Extra:    `[3]`"), "message: {message}");
-        assert!(message.contains("Missing:  `[4]`"), "message: {message}");
+        assert!(
+            message.ends_with("\n  Differences:\n    extra 3\n    missing 4"),
+            "message: {message}"
+        );
     }
 
     #[test]
@@ -328,4 +325,19 @@ mod tests {
         let s = HashSet::from([1]);
         Assert::that(&s).has_length(5);
     }
+
+    #[test]
+    #[should_panic(
+        expected = "Assertion failed: `!(actual.contains(expected))`\n  Actual:   `{1}`\n  Expected not to contain: `1`"
+    )]
+    fn not_contains_reports_full_message() {
+        Assert::that(HashSet::from([1])).not().contains(&1);
+    }
+
+    #[test]
+    fn not_flips_set_assertions() {
+        let s = HashSet::from([1, 2]);
+        Assert::that(&s).not().is_empty().not().has_length(3);
+        Assert::that(s).not().is_eq_to(HashSet::from([1]));
+    }
 }
@@ -21,6 +21,16 @@
 //! Assert::that(vec![1, 2, 3]).contains(&2);
 //! ```
 //!
+//! [`assert_that!`] labels the assertion with the asserted expression,
+//! so failures name what was being checked:
+//!
+//! ```
+//! use assert4rs::assert_that;
+//!
+//! let answer = 42;
+//! assert_that!(answer).is(42);
+//! ```
+//!
 //! Values can be transformed with [`Assert::map`] to apply further
 //! assertions:
 //!
@@ -32,17 +42,64 @@
 //!     .is(3);
 //! ```
 
+pub mod approx;
+mod collector;
+mod debug_tree;
 mod diff;
 pub mod equals;
+pub mod error;
+pub mod float;
 pub mod hashmap;
 pub mod hashset;
+mod macros;
+pub mod matcher;
+pub mod numeric;
 pub mod option;
+mod power;
+pub mod range;
+pub mod recursive;
+pub mod reporter;
 pub mod result;
-pub mod sequence;
+pub mod soft;
 pub mod string;
-mod structural_diff;
+pub mod structural_diff;
+pub mod time;
+pub mod truncate;
+mod unicode;
 pub mod vec;
 
+pub use approx::Tolerance;
+pub use error::AssertionError;
+pub use matcher::Matcher;
+pub use recursive::RecursiveComparison;
+pub use soft::SoftAssertions;
+pub use structural_diff::{Change, Comparison, Diff};
+
+#[cfg(feature = "macros")]
+pub use assert4rs_macros::{Assertable, Diff, check};
+
+/// Support code for the `check!` and `#[derive(Assertable)]` macros, not
+/// part of the public API.
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::power::{CopyValue, DebugValue, Moved, NoDebugValue, Recorded, Recorder, Wrap};
+
+    use crate::Assert;
+
+    /// Field accessor generated by `#[derive(Assertable)]`.
+    pub fn field<T, R>(
+        assert: Assert<T>,
+        root: &str,
+        field: &str,
+        f: impl FnOnce(T) -> R,
+    ) -> Assert<R> {
+        assert.map_field(root, field, f)
+    }
+}
+
+use std::panic::Location;
+use std::sync::Arc;
+
 /// Entry point for the [Assert] DSL.
 ///
 /// [Assert] provides a fluent API for assertions. An [Assert] holds
@@ -67,6 +124,9 @@ pub mod vec;
 pub struct Assert<T> {
     actual: T,
     label: Option<String>,
+    collector: Option<Arc<collector::Collector>>,
+    /// Set by [`Assert::not`] until the next assertion takes it.
+    negated: bool,
 }
 
 impl<T> Assert<T> {
@@ -77,6 +137,8 @@ impl<T> Assert<T> {
         Assert {
             actual,
             label: None,
+            collector: None,
+            negated: false,
         }
     }
 
@@ -96,8 +158,61 @@ impl<T> Assert<T> {
     /// # use assert4rs::Assert;
     /// Assert::that(2).map(|v| v + 2).is(3);
     /// ```
+    ///
+    /// The label is kept; use [`Assert::map_named`] to extend it with the
+    /// name of the mapped part.
     pub fn map<R>(self, f: impl FnOnce(T) -> R) -> Assert<R> {
-        Assert::that(f(self.actual))
+        Assert {
+            actual: f(self.actual),
+            label: self.label,
+            collector: self.collector,
+            negated: self.negated,
+        }
+    }
+
+    /// Maps the `actual` value using lambda `f`, like [`Assert::map`], and
+    /// adds `segment` to the label path, so failures name the part that
+    /// was mapped to, e.g. `resp → items`.
+    ///
+    /// ```should_panic
+    /// # use assert4rs::Assert;
+    /// # struct Response { items: Vec<u32> }
+    /// # let resp = Response { items: vec![1, 2] };
+    /// // Assertion failed for `resp → items[1].unwrap()`: `(actual == expected)`
+    /// Assert::that(resp)
+    ///     .named("resp")
+    ///     .map_named("items", |r| r.items)
+    ///     .get(1)
+    ///     .unwrap()
+    ///     .is(3);
+    /// ```
+    pub fn map_named<R>(self, segment: &str, f: impl FnOnce(T) -> R) -> Assert<R> {
+        let label = match &self.label {
+            Some(path) => format!("{path} → {segment}"),
+            None => segment.to_string(),
+        };
+        self.map(f).named(&label)
+    }
+
+    /// Maps the `actual` value using lambda `f`, appending `suffix` (e.g.
+    /// `[2]` or `.unwrap()`) to the label, if there is one.
+    pub(crate) fn map_suffixed<R>(self, suffix: &str, f: impl FnOnce(T) -> R) -> Assert<R> {
+        let label = self.label.as_ref().map(|path| format!("{path}{suffix}"));
+        Assert {
+            label,
+            ..self.map(f)
+        }
+    }
+
+    /// Maps the `actual` value to one of its fields using lambda `f`,
+    /// appending `.field` to the label, or starting the label at
+    /// `root.field` if there is none.
+    pub(crate) fn map_field<R>(self, root: &str, field: &str, f: impl FnOnce(T) -> R) -> Assert<R> {
+        let label = match &self.label {
+            Some(path) => format!("{path}.{field}"),
+            None => format!("{root}.{field}"),
+        };
+        self.map(f).named(&label)
     }
 
     /// Attach a label to this assertion, used in the panic message if
@@ -118,10 +233,120 @@ impl<T> Assert<T> {
         self
     }
 
-    pub(crate) fn header(&self, assertion: &str) -> String {
-        match &self.label {
-            Some(label) => format!("Assertion failed for `{label}`: `({assertion})`"),
-            None => format!("Assertion failed: `({assertion})`"),
+    /// Negates the next assertion in the chain: it passes where it would
+    /// have failed and the other way around, and its failure reads
+    /// `!(actual.contains(expected))`. Mappings such as [`Assert::map`],
+    /// `get` and `unwrap` pass the negation on to the assertion after
+    /// them.
+    ///
+    /// ```
+    /// # use assert4rs::Assert;
+    /// Assert::that(vec![1, 2, 3])
+    ///     .not()
+    ///     .contains(&4)
+    ///     .not()
+    ///     .is_empty()
+    ///     .contains(&3);
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use assert4rs::Assert;
+    /// Assert::that(String::from("hello")).not().starts_with("he");
+    /// ```
+    // Named after the word rather than `!`: `Assert` isn't a value to
+    // negate, and `.not()` reads as part of the chain.
+    #[allow(clippy::should_implement_trait)]
+    pub fn not(mut self) -> Self {
+        self.negated = !self.negated;
+        self
+    }
+
+    /// The value under test, for assertions written outside this crate.
+    ///
+    /// ```
+    /// # use assert4rs::Assert;
+    /// assert_eq!(*Assert::that(3).actual(), 3);
+    /// ```
+    pub fn actual(&self) -> &T {
+        &self.actual
+    }
+
+    /// Takes the negation set by [`Assert::not`] for the assertion that's
+    /// about to run, clearing it so the assertions after it aren't
+    /// negated: the assertion fails if its check comes out equal to the
+    /// returned value. Custom assertions call this first, so they can be
+    /// negated like the built-in ones.
+    pub fn take_negation(&mut self) -> bool {
+        std::mem::take(&mut self.negated)
+    }
+
+    /// Starts the [AssertionError] for a failed `assertion` on this value,
+    /// located at the caller: its header names the `assertion` and this
+    /// [Assert]'s label. Add detail lines to it and hand it to
+    /// [`Assert::fail`].
+    ///
+    /// This is what the built-in assertions use, so custom ones written
+    /// in an extension trait fail the same way (see also
+    /// [`assertion!`](crate::assertion)):
+    ///
+    /// ```should_panic
+    /// use assert4rs::Assert;
+    ///
+    /// #[derive(Debug)]
+    /// struct Order {
+    ///     paid: bool,
+    /// }
+    ///
+    /// trait OrderAssertions {
+    ///     fn is_paid(self) -> Self;
+    /// }
+    ///
+    /// impl OrderAssertions for Assert<Order> {
+    ///     #[track_caller]
+    ///     fn is_paid(mut self) -> Self {
+    ///         let negated = self.take_negation();
+    ///         if self.actual().paid == negated {
+    ///             self.fail(
+    ///                 self.error("actual.paid")
+    ///                     .negated(negated)
+    ///                     .detail("Actual", format!("{:?}", self.actual())),
+    ///             );
+    ///         }
+    ///         self
+    ///     }
+    /// }
+    ///
+    /// Assert::that(Order { paid: false }).named("order").is_paid();
+    /// ```
+    #[track_caller]
+    pub fn error(&self, assertion: &str) -> AssertionError {
+        AssertionError::new(self.label.clone(), assertion, Location::caller())
+    }
+
+    /// Reports a failed assertion. Panics with `error` (rendered by the
+    /// current [Reporter](reporter::Reporter)), or — inside
+    /// [`Assert::soft`] — records it and lets the chain carry on (inside
+    /// [`Assert::check`], recording it ends the check).
+    #[track_caller]
+    pub fn fail(&self, error: AssertionError) {
+        match &self.collector {
+            Some(collector) => collector.record(error),
+            None => panic!("{}", reporter::report(&error)),
+        }
+    }
+
+    /// Like [`Assert::fail`], for assertions that can't hand back an
+    /// [Assert] once they've failed (e.g. `unwrap` on [None]). Inside
+    /// [`Assert::soft`] this records the failure and ends the soft scope
+    /// early, so it is reported together with everything collected so far.
+    #[track_caller]
+    pub(crate) fn abort(&self, error: AssertionError) -> ! {
+        match &self.collector {
+            Some(collector) => {
+                collector.record(error);
+                collector::abort()
+            }
+            None => panic!("{}", reporter::report(&error)),
         }
     }
 }
@@ -133,18 +358,112 @@ mod tests {
     #[test]
     fn header_without_label() {
         let a = Assert::that(1);
-        assert_eq!(a.header("a == b"), "Assertion failed: `(a == b)`");
+        assert_eq!(a.error("a == b").header(), "Assertion failed: `(a == b)`");
     }
 
     #[test]
     fn header_with_label() {
         let a = Assert::that(1).named("x");
-        assert_eq!(a.header("a == b"), "Assertion failed for `x`: `(a == b)`");
+        assert_eq!(
+            a.error("a == b").header(),
+            "Assertion failed for `x`: `(a == b)`"
+        );
     }
 
     #[test]
-    fn map_resets_label() {
+    fn map_keeps_label() {
         let a = Assert::that(1).named("x").map(|v| v + 1);
-        assert_eq!(a.header("a == b"), "Assertion failed: `(a == b)`");
+        assert_eq!(
+            a.error("a == b").header(),
+            "Assertion failed for `x`: `(a == b)`"
+        );
+    }
+
+    #[test]
+    fn map_named_extends_label_path() {
+        let a = Assert::that((1, 2))
+            .named("pair")
+            .map_named("first", |p| p.0);
+        assert_eq!(
+            a.error("a == b").header(),
+            "Assertion failed for `pair → first`: `(a == b)`"
+        );
+    }
+
+    #[test]
+    fn map_named_starts_label_path() {
+        let a = Assert::that((1, 2)).map_named("first", |p| p.0);
+        assert_eq!(
+            a.error("a == b").header(),
+            "Assertion failed for `first`: `(a == b)`"
+        );
+    }
+
+    #[test]
+    fn label_path_composes_through_get_and_unwrap() {
+        struct Response {
+            items: Vec<Option<u32>>,
+        }
+        let resp = Response {
+            items: vec![Some(1), None, Some(3)],
+        };
+        let error = Assert::check(resp, |a| {
+            a.named("resp")
+                .map_named("items", |r| r.items)
+                .get(2)
+                .unwrap()
+                .unwrap()
+                .is(4)
+        })
+        .err()
+        .unwrap();
+        assert_eq!(error.label(), Some("resp → items[2].unwrap().unwrap()"));
+    }
+
+    #[test]
+    fn unlabelled_get_and_unwrap_stay_unlabelled() {
+        let error = Assert::check(vec![1], |a| a.get(0).unwrap().is(2))
+            .err()
+            .unwrap();
+        assert_eq!(error.label(), None);
+    }
+
+    #[test]
+    fn not_negates_only_the_next_assertion() {
+        let error = Assert::check(vec![1, 2], |a| a.not().contains(&3).contains(&3))
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.header(),
+            "Assertion failed: `(actual.contains(expected))`"
+        );
+    }
+
+    #[test]
+    fn double_not_cancels_out() {
+        Assert::that(3).not().not().is(3);
+    }
+
+    #[test]
+    fn take_negation_clears_it() {
+        let mut a = Assert::that(3).not();
+        assert!(a.take_negation());
+        assert!(!a.take_negation());
+    }
+
+    #[test]
+    fn assert_is_send_and_sync() {
+        fn send_and_sync<T: Send + Sync>(_: &T) {}
+        send_and_sync(&Assert::that(3).not());
+    }
+
+    #[test]
+    fn not_carries_through_mappings() {
+        let error = Assert::check(vec![Some(1)], |a| a.not().get(0).unwrap().unwrap().is(1))
+            .err()
+            .unwrap();
+        assert_eq!(error.header(), "Assertion failed: `!(actual == expected)`");
+        assert_eq!(error.assertion(), "!(actual == expected)");
+        assert_eq!(error.expected(), Some("not 1"));
     }
 }
@@ -0,0 +1,214 @@
+//! Macros that save typing out what the compiler already knows.
+
+/// Create an [Assert](crate::Assert) for an expression, labelled with the
+/// expression's source text.
+///
+/// `assert_that!(user.age)` is short for
+/// `Assert::that(user.age).named(stringify!(user.age))`, so a failure
+/// reads ``Assertion failed for `user.age` `` without repeating the
+/// expression by hand.
+///
+/// ```
+/// # use assert4rs::assert_that;
+/// # struct User { age: u32 }
+/// let user = User { age: 30 };
+/// assert_that!(user.age).is(30);
+/// ```
+///
+/// ```should_panic
+/// # use assert4rs::assert_that;
+/// # struct User { age: u32 }
+/// let user = User { age: 25 };
+/// assert_that!(user.age).is(30);
+/// ```
+#[macro_export]
+macro_rules! assert_that {
+    ($actual:expr $(,)?) => {
+        $crate::Assert::that($actual).named(::std::stringify!($actual))
+    };
+}
+
+/// Define domain assertions for your own types, failing with the same
+/// message layout as the built-in ones.
+///
+/// Each `fn name(&self, args…) -> condition; "description"` becomes a
+/// `#[track_caller]` method of an extension trait implemented for
+/// [`Assert<Type>`](crate::Assert). Within `condition`, `self` is the
+/// value under test. A failure shows the condition, the actual value, the
+/// description as what was expected, and the arguments:
+///
+/// ```
+/// use assert4rs::{Assert, assertion};
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Status { Open, Paid }
+///
+/// #[derive(Debug)]
+/// struct Order { status: Status, total: u32 }
+///
+/// assertion! {
+///     /// Assertions on an [Order].
+///     pub trait OrderAssertions for Order {
+///         fn is_paid(&self) -> self.status == Status::Paid; "order is paid"
+///         fn has_total(&self, total: u32) -> self.total == total; "order has the given total"
+///     }
+/// }
+///
+/// let order = Order { status: Status::Paid, total: 42 };
+/// Assert::that(order).is_paid().has_total(42).not().has_total(7);
+/// ```
+///
+/// ```should_panic
+/// # use assert4rs::{Assert, assertion};
+/// # #[derive(Debug, PartialEq)]
+/// # enum Status { Open, Paid }
+/// # #[derive(Debug)]
+/// # struct Order { status: Status, total: u32 }
+/// # assertion! {
+/// #     pub trait OrderAssertions for Order {
+/// #         fn is_paid(&self) -> self.status == Status::Paid; "order is paid"
+/// #     }
+/// # }
+/// // Assertion failed for `order`: `(self.status == Status::Paid)`
+/// //   Actual:   `Order { status: Open, total: 42 }`
+/// //   Expected: `order is paid`
+/// let order = Order { status: Status::Open, total: 42 };
+/// Assert::that(order).named("order").is_paid();
+/// ```
+///
+/// The generated assertions take part in [`Assert::not`](crate::Assert::not),
+/// soft assertions and [`Assert::check`](crate::Assert::check) like any
+/// other. The type must implement [Debug](std::fmt::Debug), as must the
+/// arguments, and can't have generic parameters.
+#[macro_export]
+macro_rules! assertion {
+    (
+        $(#[$trait_attr:meta])*
+        $vis:vis trait $trait_name:ident for $target:ty {
+            $(
+                $(#[$attr:meta])*
+                fn $method:ident(&$this:ident $(, $arg:ident: $arg_ty:ty)* $(,)?) -> $condition:expr; $description:literal
+            )*
+        }
+    ) => {
+        $(#[$trait_attr])*
+        $vis trait $trait_name {
+            $(
+                $(#[$attr])*
+                #[track_caller]
+                fn $method(self $(, $arg: $arg_ty)*) -> Self;
+            )*
+        }
+
+        impl $trait_name for $crate::Assert<$target> {
+            $(
+                #[track_caller]
+                fn $method(mut self $(, $arg: $arg_ty)*) -> Self {
+                    // Lets `self` in the condition be the value under test.
+                    trait Condition {
+                        fn holds(&self $(, $arg: $arg_ty)*) -> bool;
+                    }
+                    impl Condition for $target {
+                        fn holds(&$this $(, $arg: $arg_ty)*) -> bool {
+                            $condition
+                        }
+                    }
+                    let arguments: &[(&str, ::std::string::String)] =
+                        &[$((::std::stringify!($arg), ::std::format!("{:?}", $arg))),*];
+                    let negated = self.take_negation();
+                    if Condition::holds(self.actual() $(, $arg)*) == negated {
+                        let expected = if negated {
+                            ::std::format!("not {}", $description)
+                        } else {
+                            ::std::string::String::from($description)
+                        };
+                        let mut error = self
+                            .error(::std::stringify!($condition))
+                            .negated(negated)
+                            .aligned_detail("Actual", ::std::format!("{:?}", self.actual()), "Expected")
+                            .detail("Expected", expected);
+                        for (name, value) in arguments {
+                            error = error.aligned_detail(name, value.clone(), "Expected");
+                        }
+                        self.fail(error);
+                    }
+                    self
+                }
+            )*
+        }
+    };
+}
+
+/// Implement [Diff](crate::Diff) for types that are compared as a whole,
+/// with `==`, rather than part by part, so that
+/// [`Assert::is_eq_to`](crate::Assert::is_eq_to) works on them and on
+/// lists, maps and tuples of them, without the `macros` feature.
+///
+/// A differing value is listed as a whole, e.g. `[2]: Point { x: 1 } !=
+/// Point { x: 2 }`. Comparators registered with
+/// [`with_comparator_for`](crate::RecursiveComparison::with_comparator_for)
+/// replace `==`. The types must implement [Debug](std::fmt::Debug) and
+/// [PartialEq], and can't borrow anything.
+///
+/// ```
+/// use assert4rs::{Assert, diff_as_whole};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Point { x: i32, y: i32 }
+///
+/// diff_as_whole!(Point);
+///
+/// Assert::that(vec![Point { x: 1, y: 2 }]).is_eq_to(vec![Point { x: 1, y: 2 }]);
+/// ```
+#[macro_export]
+macro_rules! diff_as_whole {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl $crate::Diff for $ty {
+                fn diff_with(
+                    &self,
+                    other: &Self,
+                    comparison: &$crate::Comparison,
+                ) -> ::std::vec::Vec<$crate::Change> {
+                    comparison.whole(self, other)
+                }
+
+                fn eq_with(&self, other: &Self, comparison: &$crate::Comparison) -> bool {
+                    comparison.whole_eq(self, other)
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    struct User {
+        age: u32,
+        name: &'static str,
+    }
+
+    const USER: User = User {
+        age: 25,
+        name: "Alice",
+    };
+
+    #[test]
+    #[should_panic(
+        expected = "Assertion failed for `USER.age`: `(actual == expected)`\n  Actual:   `25`\n  Expected: `30`"
+    )]
+    fn labels_with_source_expression() {
+        crate::assert_that!(USER.age).is(30);
+    }
+
+    #[test]
+    #[should_panic(expected = "Assertion failed for `USER.name.len()`: `(actual > other)`")]
+    fn labels_method_calls() {
+        crate::assert_that!(USER.name.len()).is_gt(10);
+    }
+
+    #[test]
+    fn passes_through_on_success() {
+        crate::assert_that!(USER.name).is("Alice").is_not("Bob");
+    }
+}
@@ -0,0 +1,789 @@
+//! Hamcrest-style matchers: assertions as values that can be combined,
+//! stored and reused, then applied with [`Assert::matches`] or passed to
+//! collection assertions like [`all_match`](Assert::all_match).
+//!
+//! ```
+//! use assert4rs::Assert;
+//! use assert4rs::matcher::{all_of, gt, satisfies};
+//!
+//! let big_and_even = all_of((gt(3), satisfies("an even number", |v: &i32| v % 2 == 0)));
+//! Assert::that(4).matches(&big_and_even);
+//! Assert::that(vec![4, 6, 8]).all_match(&big_and_even);
+//! ```
+
+use crate::Assert;
+use std::cmp::Ordering;
+use std::fmt::Debug;
+
+/// A condition on values of type `T` that can describe itself, used with
+/// [`Assert::matches`].
+pub trait Matcher<T: ?Sized> {
+    /// Whether `actual` satisfies the condition.
+    fn matches(&self, actual: &T) -> bool;
+
+    /// What a matching value looks like, e.g. `greater than 3`.
+    fn describe(&self) -> String;
+
+    /// Why `actual` doesn't match, e.g. `was 2`.
+    fn describe_mismatch(&self, actual: &T) -> String;
+}
+
+impl<T: ?Sized, M: Matcher<T> + ?Sized> Matcher<T> for &M {
+    fn matches(&self, actual: &T) -> bool {
+        (**self).matches(actual)
+    }
+
+    fn describe(&self) -> String {
+        (**self).describe()
+    }
+
+    fn describe_mismatch(&self, actual: &T) -> String {
+        (**self).describe_mismatch(actual)
+    }
+}
+
+impl<T: ?Sized, M: Matcher<T> + ?Sized> Matcher<T> for Box<M> {
+    fn matches(&self, actual: &T) -> bool {
+        (**self).matches(actual)
+    }
+
+    fn describe(&self) -> String {
+        (**self).describe()
+    }
+
+    fn describe_mismatch(&self, actual: &T) -> String {
+        (**self).describe_mismatch(actual)
+    }
+}
+
+/// The usual mismatch description: `was` and the `{:?}` of `actual`.
+fn was<T: Debug + ?Sized>(actual: &T) -> String {
+    format!("was {actual:?}")
+}
+
+impl<T: Debug> Assert<T> {
+    /// Assert that the actual value matches `matcher`.
+    ///
+    /// ```
+    /// # use assert4rs::Assert;
+    /// use assert4rs::matcher::{any_of, eq, gt};
+    /// Assert::that(5).matches(gt(3));
+    /// Assert::that(0).matches(any_of((eq(0), gt(3))));
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use assert4rs::Assert;
+    /// use assert4rs::matcher::gt;
+    /// Assert::that(2).matches(gt(3));
+    /// ```
+    #[track_caller]
+    pub fn matches(mut self, matcher: impl Matcher<T>) -> Self {
+        let negated = self.take_negation();
+        if matcher.matches(&self.actual) == negated {
+            let error = self
+                .error("matcher.matches(actual)")
+                .negated(negated)
+                .aligned_detail("Actual", format!("{:?}", self.actual), "Expected");
+            self.fail(if negated {
+                error.detail("Expected", format!("not {}", matcher.describe()))
+            } else {
+                error
+                    .aligned_detail("Expected", matcher.describe(), "Mismatch")
+                    .aligned_detail(
+                        "Mismatch",
+                        matcher.describe_mismatch(&self.actual),
+                        "Expected",
+                    )
+            });
+        }
+        self
+    }
+}
+
+/// Matches values equal to the expected value, see [eq].
+pub struct Eq<E>(E);
+
+/// Matches values equal to `expected`, like [`Assert::is`].
+///
+/// ```
+/// # use assert4rs::Assert;
+/// use assert4rs::matcher::eq;
+/// Assert::that(String::from("a")).matches(eq("a"));
+/// ```
+pub fn eq<E>(expected: E) -> Eq<E> {
+    Eq(expected)
+}
+
+impl<T: PartialEq<E> + Debug + ?Sized, E: Debug> Matcher<T> for Eq<E> {
+    fn matches(&self, actual: &T) -> bool {
+        *actual == self.0
+    }
+
+    fn describe(&self) -> String {
+        format!("equal to {:?}", self.0)
+    }
+
+    fn describe_mismatch(&self, actual: &T) -> String {
+        was(actual)
+    }
+}
+
+/// Matches values not equal to the given value, see [ne].
+pub struct Ne<E>(E);
+
+/// Matches values not equal to `other`, like [`Assert::is_not`].
+///
+/// ```
+/// # use assert4rs::Assert;
+/// use assert4rs::matcher::ne;
+/// Assert::that(String::from("a")).matches(ne("b"));
+/// ```
+pub fn ne<E>(other: E) -> Ne<E> {
+    Ne(other)
+}
+
+impl<T: PartialEq<E> + Debug + ?Sized, E: Debug> Matcher<T> for Ne<E> {
+    fn matches(&self, actual: &T) -> bool {
+        *actual != self.0
+    }
+
+    fn describe(&self) -> String {
+        format!("not equal to {:?}", self.0)
+    }
+
+    fn describe_mismatch(&self, actual: &T) -> String {
+        was(actual)
+    }
+}
+
+/// Matches values in a given order relative to a bound, see [gt], [ge],
+/// [lt] and [le].
+pub struct Compare<E> {
+    bound: E,
+    relation: &'static str,
+    accepts: fn(Option<Ordering>) -> bool,
+}
+
+impl<T: PartialOrd<E> + Debug + ?Sized, E: Debug> Matcher<T> for Compare<E> {
+    fn matches(&self, actual: &T) -> bool {
+        (self.accepts)(actual.partial_cmp(&self.bound))
+    }
+
+    fn describe(&self) -> String {
+        format!("{} {:?}", self.relation, self.bound)
+    }
+
+    fn describe_mismatch(&self, actual: &T) -> String {
+        was(actual)
+    }
+}
+
+/// Matches values greater than `other`, like [`Assert::is_gt`].
+///
+/// ```
+/// # use assert4rs::Assert;
+/// use assert4rs::matcher::gt;
+/// Assert::that(3).matches(gt(2));
+/// ```
+pub fn gt<E>(other: E) -> Compare<E> {
+    Compare {
+        bound: other,
+        relation: "greater than",
+        accepts: |ordering| ordering == Some(Ordering::Greater),
+    }
+}
+
+/// Matches values greater than or equal to `other`, like
+/// [`Assert::is_ge`].
+pub fn ge<E>(other: E) -> Compare<E> {
+    Compare {
+        bound: other,
+        relation: "greater than or equal to",
+        accepts: |ordering| matches!(ordering, Some(Ordering::Greater | Ordering::Equal)),
+    }
+}
+
+/// Matches values less than `other`, like [`Assert::is_lt`].
+pub fn lt<E>(other: E) -> Compare<E> {
+    Compare {
+        bound: other,
+        relation: "less than",
+        accepts: |ordering| ordering == Some(Ordering::Less),
+    }
+}
+
+/// Matches values less than or equal to `other`, like [`Assert::is_le`].
+pub fn le<E>(other: E) -> Compare<E> {
+    Compare {
+        bound: other,
+        relation: "less than or equal to",
+        accepts: |ordering| matches!(ordering, Some(Ordering::Less | Ordering::Equal)),
+    }
+}
+
+/// Matches values for which a predicate holds, see [satisfies].
+pub struct Satisfies<F> {
+    description: String,
+    predicate: F,
+}
+
+/// Matches values for which `predicate` holds, like
+/// [`Assert::satisfies`]. `description` says what such a value is, e.g.
+/// `an even number`.
+///
+/// ```
+/// # use assert4rs::Assert;
+/// use assert4rs::matcher::satisfies;
+/// Assert::that(4).matches(satisfies("an even number", |v: &i32| v % 2 == 0));
+/// ```
+pub fn satisfies<T: ?Sized, F: Fn(&T) -> bool>(description: &str, predicate: F) -> Satisfies<F> {
+    Satisfies {
+        description: description.to_string(),
+        predicate,
+    }
+}
+
+impl<T: Debug + ?Sized, F: Fn(&T) -> bool> Matcher<T> for Satisfies<F> {
+    fn matches(&self, actual: &T) -> bool {
+        (self.predicate)(actual)
+    }
+
+    fn describe(&self) -> String {
+        self.description.clone()
+    }
+
+    fn describe_mismatch(&self, actual: &T) -> String {
+        was(actual)
+    }
+}
+
+/// Matches any value, see [anything].
+pub struct Anything;
+
+/// Matches any value, e.g. as the inner matcher of [some] to only check
+/// for [Some].
+pub fn anything() -> Anything {
+    Anything
+}
+
+impl<T: ?Sized> Matcher<T> for Anything {
+    fn matches(&self, _actual: &T) -> bool {
+        true
+    }
+
+    fn describe(&self) -> String {
+        String::from("anything")
+    }
+
+    fn describe_mismatch(&self, _actual: &T) -> String {
+        String::new()
+    }
+}
+
+/// Matches strings and collections containing an item, see [contains].
+pub struct Contains<P>(P);
+
+/// Matches collections ([Vec], slices and arrays) that contain `item`,
+/// and strings that contain the substring `item`.
+///
+/// ```
+/// # use assert4rs::Assert;
+/// use assert4rs::matcher::contains;
+/// Assert::that(vec![1, 2, 3]).matches(contains(2));
+/// Assert::that(String::from("hello world")).matches(contains("o w"));
+/// ```
+pub fn contains<P>(item: P) -> Contains<P> {
+    Contains(item)
+}
+
+impl<U: PartialEq + Debug> Matcher<Vec<U>> for Contains<U> {
+    fn matches(&self, actual: &Vec<U>) -> bool {
+        actual.contains(&self.0)
+    }
+
+    fn describe(&self) -> String {
+        format!("containing {:?}", self.0)
+    }
+
+    fn describe_mismatch(&self, actual: &Vec<U>) -> String {
+        format!("was {}", crate::truncate::list(actual.iter()))
+    }
+}
+
+impl<U: PartialEq + Debug> Matcher<[U]> for Contains<U> {
+    fn matches(&self, actual: &[U]) -> bool {
+        actual.contains(&self.0)
+    }
+
+    fn describe(&self) -> String {
+        format!("containing {:?}", self.0)
+    }
+
+    fn describe_mismatch(&self, actual: &[U]) -> String {
+        format!("was {}", crate::truncate::list(actual.iter()))
+    }
+}
+
+impl<U: PartialEq + Debug, const N: usize> Matcher<[U; N]> for Contains<U> {
+    fn matches(&self, actual: &[U; N]) -> bool {
+        actual.contains(&self.0)
+    }
+
+    fn describe(&self) -> String {
+        format!("containing {:?}", self.0)
+    }
+
+    fn describe_mismatch(&self, actual: &[U; N]) -> String {
+        format!("was {}", crate::truncate::list(actual.iter()))
+    }
+}
+
+/// Implements a string [Matcher] for [String], `&String`, [str] and
+/// `&str` in terms of a `str` predicate.
+macro_rules! string_matcher {
+    ($matcher:ident, $relation:literal, |$actual:ident, $pattern:ident| $test:expr) => {
+        string_matcher!(@impl $matcher, String, $relation, |$actual, $pattern| $test);
+        string_matcher!(@impl $matcher, &String, $relation, |$actual, $pattern| $test);
+        string_matcher!(@impl $matcher, str, $relation, |$actual, $pattern| $test);
+        string_matcher!(@impl $matcher, &str, $relation, |$actual, $pattern| $test);
+    };
+    (@impl $matcher:ident, $string:ty, $relation:literal, |$actual:ident, $pattern:ident| $test:expr) => {
+        impl<P: AsRef<str> + Debug> Matcher<$string> for $matcher<P> {
+            fn matches(&self, actual: &$string) -> bool {
+                let $actual: &str = actual.as_ref();
+                let $pattern: &str = self.0.as_ref();
+                $test
+            }
+
+            fn describe(&self) -> String {
+                format!(concat!($relation, " {:?}"), self.0)
+            }
+
+            fn describe_mismatch(&self, actual: &$string) -> String {
+                was(actual)
+            }
+        }
+    };
+}
+
+string_matcher!(Contains, "containing", |actual, pattern| actual
+    .contains(pattern));
+
+/// Matches strings starting with a prefix, see [starts_with].
+pub struct StartsWith<P>(P);
+
+/// Matches strings starting with `prefix`, like
+/// [`Assert::starts_with`](Assert::<String>::starts_with).
+pub fn starts_with<P: AsRef<str>>(prefix: P) -> StartsWith<P> {
+    StartsWith(prefix)
+}
+
+string_matcher!(StartsWith, "starting with", |actual, prefix| actual
+    .starts_with(prefix));
+
+/// Matches strings ending with a suffix, see [ends_with].
+pub struct EndsWith<P>(P);
+
+/// Matches strings ending with `suffix`, like
+/// [`Assert::ends_with`](Assert::<String>::ends_with).
+pub fn ends_with<P: AsRef<str>>(suffix: P) -> EndsWith<P> {
+    EndsWith(suffix)
+}
+
+string_matcher!(EndsWith, "ending with", |actual, suffix| actual
+    .ends_with(suffix));
+
+/// Matches [Some] value matching an inner matcher, see [some].
+pub struct IsSome<M>(M);
+
+/// Matches [Some] value that matches `matcher`; use [anything] to accept
+/// any value.
+///
+/// ```
+/// # use assert4rs::Assert;
+/// use assert4rs::matcher::{gt, some};
+/// Assert::that(Some(5)).matches(some(gt(3)));
+/// ```
+pub fn some<M>(matcher: M) -> IsSome<M> {
+    IsSome(matcher)
+}
+
+impl<T: Debug, M: Matcher<T>> Matcher<Option<T>> for IsSome<M> {
+    fn matches(&self, actual: &Option<T>) -> bool {
+        actual.as_ref().is_some_and(|value| self.0.matches(value))
+    }
+
+    fn describe(&self) -> String {
+        format!("Some({})", self.0.describe())
+    }
+
+    fn describe_mismatch(&self, actual: &Option<T>) -> String {
+        match actual {
+            Some(value) => format!("Some value {}", self.0.describe_mismatch(value)),
+            None => was(actual),
+        }
+    }
+}
+
+/// Matches [None], see [none].
+pub struct IsNone;
+
+/// Matches [None].
+pub fn none() -> IsNone {
+    IsNone
+}
+
+impl<T: Debug> Matcher<Option<T>> for IsNone {
+    fn matches(&self, actual: &Option<T>) -> bool {
+        actual.is_none()
+    }
+
+    fn describe(&self) -> String {
+        String::from("None")
+    }
+
+    fn describe_mismatch(&self, actual: &Option<T>) -> String {
+        was(actual)
+    }
+}
+
+/// Matches an [Ok] value matching an inner matcher, see [ok].
+pub struct IsOk<M>(M);
+
+/// Matches an [Ok] value that matches `matcher`.
+///
+/// ```
+/// # use assert4rs::Assert;
+/// use assert4rs::matcher::{eq, ok};
+/// Assert::that("42".parse::<i32>()).matches(ok(eq(42)));
+/// ```
+pub fn ok<M>(matcher: M) -> IsOk<M> {
+    IsOk(matcher)
+}
+
+impl<T: Debug, E: Debug, M: Matcher<T>> Matcher<Result<T, E>> for IsOk<M> {
+    fn matches(&self, actual: &Result<T, E>) -> bool {
+        actual.as_ref().is_ok_and(|value| self.0.matches(value))
+    }
+
+    fn describe(&self) -> String {
+        format!("Ok({})", self.0.describe())
+    }
+
+    fn describe_mismatch(&self, actual: &Result<T, E>) -> String {
+        match actual {
+            Ok(value) => format!("Ok value {}", self.0.describe_mismatch(value)),
+            Err(_) => was(actual),
+        }
+    }
+}
+
+/// Matches an [Err] value matching an inner matcher, see [err].
+pub struct IsErr<M>(M);
+
+/// Matches an [Err] value that matches `matcher`.
+pub fn err<M>(matcher: M) -> IsErr<M> {
+    IsErr(matcher)
+}
+
+impl<T: Debug, E: Debug, M: Matcher<E>> Matcher<Result<T, E>> for IsErr<M> {
+    fn matches(&self, actual: &Result<T, E>) -> bool {
+        actual.as_ref().is_err_and(|error| self.0.matches(error))
+    }
+
+    fn describe(&self) -> String {
+        format!("Err({})", self.0.describe())
+    }
+
+    fn describe_mismatch(&self, actual: &Result<T, E>) -> String {
+        match actual {
+            Err(error) => format!("Err value {}", self.0.describe_mismatch(error)),
+            Ok(_) => was(actual),
+        }
+    }
+}
+
+/// A list of matchers for the same type, combined by [all_of] and
+/// [any_of]: a tuple of up to eight matchers, or an array or [Vec] of
+/// matchers of one type (e.g. `Box<dyn Matcher<T>>`).
+pub trait Matchers<T: ?Sized> {
+    /// The matchers in the list.
+    fn each(&self) -> Vec<&dyn Matcher<T>>;
+}
+
+impl<T: ?Sized, M: Matcher<T>, const N: usize> Matchers<T> for [M; N] {
+    fn each(&self) -> Vec<&dyn Matcher<T>> {
+        self.iter().map(|m| m as &dyn Matcher<T>).collect()
+    }
+}
+
+impl<T: ?Sized, M: Matcher<T>> Matchers<T> for Vec<M> {
+    fn each(&self) -> Vec<&dyn Matcher<T>> {
+        self.iter().map(|m| m as &dyn Matcher<T>).collect()
+    }
+}
+
+macro_rules! tuple_matchers {
+    ($($m:ident $i:tt),+) => {
+        impl<T: ?Sized, $($m: Matcher<T>),+> Matchers<T> for ($($m,)+) {
+            fn each(&self) -> Vec<&dyn Matcher<T>> {
+                vec![$(&self.$i),+]
+            }
+        }
+    };
+}
+
+tuple_matchers!(A 0);
+tuple_matchers!(A 0, B 1);
+tuple_matchers!(A 0, B 1, C 2);
+tuple_matchers!(A 0, B 1, C 2, D 3);
+tuple_matchers!(A 0, B 1, C 2, D 3, E 4);
+tuple_matchers!(A 0, B 1, C 2, D 3, E 4, F 5);
+tuple_matchers!(A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+tuple_matchers!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+
+/// Matches values that match every matcher in a list, see [all_of].
+pub struct AllOf<L>(L);
+
+/// Matches values that match every one of `matchers`.
+///
+/// ```
+/// # use assert4rs::Assert;
+/// use assert4rs::matcher::{all_of, gt, lt};
+/// Assert::that(5).matches(all_of((gt(3), lt(10))));
+/// ```
+///
+/// ```should_panic
+/// # use assert4rs::Assert;
+/// use assert4rs::matcher::{all_of, gt, lt};
+/// Assert::that(12).matches(all_of((gt(3), lt(10))));
+/// ```
+pub fn all_of<L>(matchers: L) -> AllOf<L> {
+    AllOf(matchers)
+}
+
+impl<T: ?Sized, L: Matchers<T>> Matcher<T> for AllOf<L> {
+    fn matches(&self, actual: &T) -> bool {
+        self.0.each().iter().all(|m| m.matches(actual))
+    }
+
+    fn describe(&self) -> String {
+        join(&self.0.each(), " and ")
+    }
+
+    fn describe_mismatch(&self, actual: &T) -> String {
+        let each = self.0.each();
+        match each.iter().find(|m| !m.matches(actual)) {
+            Some(m) => format!("{} {}", m.describe(), m.describe_mismatch(actual)),
+            None => String::new(),
+        }
+    }
+}
+
+/// Matches values that match at least one matcher in a list, see
+/// [any_of].
+pub struct AnyOf<L>(L);
+
+/// Matches values that match at least one of `matchers`.
+///
+/// ```
+/// # use assert4rs::Assert;
+/// use assert4rs::matcher::{any_of, eq, gt};
+/// Assert::that(0).matches(any_of((eq(0), gt(3))));
+/// ```
+///
+/// ```should_panic
+/// # use assert4rs::Assert;
+/// use assert4rs::matcher::{any_of, eq, gt};
+/// Assert::that(2).matches(any_of((eq(0), gt(3))));
+/// ```
+pub fn any_of<L>(matchers: L) -> AnyOf<L> {
+    AnyOf(matchers)
+}
+
+impl<T: Debug + ?Sized, L: Matchers<T>> Matcher<T> for AnyOf<L> {
+    fn matches(&self, actual: &T) -> bool {
+        self.0.each().iter().any(|m| m.matches(actual))
+    }
+
+    fn describe(&self) -> String {
+        join(&self.0.each(), " or ")
+    }
+
+    fn describe_mismatch(&self, actual: &T) -> String {
+        was(actual)
+    }
+}
+
+/// Describes `matchers` joined by `separator`, in parentheses if there's
+/// more than one.
+fn join<T: ?Sized>(matchers: &[&dyn Matcher<T>], separator: &str) -> String {
+    let described: Vec<String> = matchers.iter().map(|m| m.describe()).collect();
+    match described.len() {
+        1 => described.into_iter().next().unwrap_or_default(),
+        _ => format!("({})", described.join(separator)),
+    }
+}
+
+/// Matches values that don't match an inner matcher, see [not].
+pub struct Not<M>(M);
+
+/// Matches values that don't match `matcher`.
+///
+/// ```
+/// # use assert4rs::Assert;
+/// use assert4rs::matcher::{contains, not};
+/// Assert::that(vec![1, 2]).matches(not(contains(3)));
+/// ```
+pub fn not<M>(matcher: M) -> Not<M> {
+    Not(matcher)
+}
+
+impl<T: Debug + ?Sized, M: Matcher<T>> Matcher<T> for Not<M> {
+    fn matches(&self, actual: &T) -> bool {
+        !self.0.matches(actual)
+    }
+
+    fn describe(&self) -> String {
+        format!("not {}", self.0.describe())
+    }
+
+    fn describe_mismatch(&self, actual: &T) -> String {
+        was(actual)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    fn failure<T: Debug>(actual: T, matcher: impl Matcher<T>) -> crate::AssertionError {
+        Assert::check(actual, |a| a.matches(matcher)).err().unwrap()
+    }
+
+    #[test]
+    #[should_panic(expected = "Assertion failed: `(matcher.matches(actual))`")]
+    fn matches_reports_header() {
+        Assert::that(2).matches(gt(3));
+    }
+
+    #[test]
+    fn matches_reports_description_and_mismatch() {
+        let error = failure(2, gt(3));
+        assert_eq!(error.actual(), Some("2"));
+        assert_eq!(error.expected(), Some("greater than 3"));
+        assert!(
+            error
+                .to_string()
+                .contains("\n  Actual:   `2`\n  Expected: `greater than 3`\n  Mismatch: `was 2`"),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn all_of_reports_first_failing_matcher() {
+        let even = satisfies("an even number", |v: &i32| v % 2 == 0);
+        let error = failure(5, all_of((gt(3), even)));
+        assert_eq!(
+            error.expected(),
+            Some("(greater than 3 and an even number)")
+        );
+        assert!(
+            error
+                .to_string()
+                .contains("Mismatch: `an even number was 5`"),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn any_of_needs_one_match() {
+        Assert::that(7).matches(any_of([lt(0), gt(5)]));
+        let error = failure(3, any_of([lt(0), gt(5)]));
+        assert_eq!(error.expected(), Some("(less than 0 or greater than 5)"));
+    }
+
+    #[test]
+    fn not_negates() {
+        Assert::that(3).matches(not(eq(4)));
+        let error = failure(4, not(eq(4)));
+        assert_eq!(error.expected(), Some("not equal to 4"));
+    }
+
+    #[test]
+    fn comparisons_match_like_their_assertions() {
+        Assert::that(3)
+            .matches(ge(3))
+            .matches(le(3))
+            .matches(lt(4))
+            .matches(ne(4));
+        Assert::that(f64::NAN)
+            .matches(ne(1.0))
+            .matches(not(ge(1.0)));
+    }
+
+    #[test]
+    fn ne_matches_values_that_are_only_partial_eq() {
+        let set: HashSet<i32> = [1, 2].into();
+        Assert::that(set.clone())
+            .matches(ne(HashSet::from([1])))
+            .matches(not(ne(set)));
+        Assert::that(f64::NAN).matches(ne(f64::NAN));
+        let error = failure("a", ne("a"));
+        assert_eq!(error.expected(), Some("not equal to \"a\""));
+    }
+
+    #[test]
+    fn string_matchers_accept_any_string_type() {
+        Assert::that(String::from("hello world"))
+            .matches(starts_with("hello"))
+            .matches(ends_with(String::from("world")))
+            .matches(contains("o w"));
+        Assert::that("hello").matches(starts_with("he"));
+        let error = failure("hello", ends_with("x"));
+        assert_eq!(error.expected(), Some("ending with \"x\""));
+    }
+
+    #[test]
+    fn collection_contains() {
+        Assert::that(vec![1, 2, 3]).matches(contains(2));
+        Assert::that([1, 2, 3]).matches(contains(3));
+        let error = failure(vec![1, 2], contains(3));
+        assert!(
+            error.to_string().contains("Mismatch: `was [1, 2]`"),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn option_and_result_matchers() {
+        Assert::that(Some(5))
+            .matches(some(gt(3)))
+            .matches(some(anything()));
+        Assert::that(None::<i32>).matches(none());
+        Assert::that(Ok::<i32, String>(1)).matches(ok(eq(1)));
+        Assert::that(Err::<i32, &str>("boom")).matches(err(contains("oo")));
+
+        let error = failure(Some(2), some(gt(3)));
+        assert_eq!(error.expected(), Some("Some(greater than 3)"));
+        assert!(
+            error.to_string().contains("Mismatch: `Some value was 2`"),
+            "{error}"
+        );
+        let error = failure(None::<i32>, some(gt(3)));
+        assert!(
+            error.to_string().contains("Mismatch: `was None`"),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn boxed_matchers_can_be_stored_and_reused() {
+        let checks: Vec<Box<dyn Matcher<i32>>> = vec![Box::new(gt(0)), Box::new(lt(10))];
+        let in_range = all_of(checks);
+        Assert::that(1).matches(&in_range);
+        Assert::that(9).matches(&in_range);
+        assert!(!in_range.matches(&10));
+    }
+}
@@ -0,0 +1,450 @@
+//! Assertions on numbers: sign and zero checks for integers and floats,
+//! and parity, divisibility and power-of-two checks for integers.
+
+use std::cmp::Ordering;
+use std::fmt::Debug;
+use std::num::{NonZero, Wrapping};
+
+use crate::Assert;
+use crate::equals::INCOMPARABLE;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Numbers that [`Assert::is_positive`] and the other sign and zero
+/// assertions apply to: the integer and float primitives, and
+/// [NonZero] and [Wrapping] integers.
+pub trait Numeric: Copy + Debug + sealed::Sealed {
+    /// How the number compares to zero, or `None` for NaN.
+    #[doc(hidden)]
+    fn compare_to_zero(self) -> Option<Ordering>;
+}
+
+/// Integers that [`Assert::is_even`] and the other divisibility
+/// assertions apply to: the integer primitives, and [NonZero] and
+/// [Wrapping] integers.
+pub trait Integer: Numeric {
+    /// The primitive integer type, of divisors.
+    type Primitive: Copy + Debug;
+
+    /// Whether the number is a multiple of `divisor`. Only zero is a
+    /// multiple of zero.
+    #[doc(hidden)]
+    fn is_multiple_of(self, divisor: Self::Primitive) -> bool;
+
+    /// Whether the number is even.
+    #[doc(hidden)]
+    fn is_even(self) -> bool;
+
+    /// Whether the number is a positive power of two.
+    #[doc(hidden)]
+    fn is_power_of_two(self) -> bool;
+}
+
+macro_rules! signed {
+    ($($int:ty),* $(,)?) => {
+        $(
+            impl sealed::Sealed for $int {}
+
+            impl Numeric for $int {
+                fn compare_to_zero(self) -> Option<Ordering> {
+                    Some(self.cmp(&0))
+                }
+            }
+
+            impl Integer for $int {
+                type Primitive = $int;
+
+                fn is_multiple_of(self, divisor: $int) -> bool {
+                    match divisor {
+                        0 => self == 0,
+                        // Wrapping, as `MIN % -1` overflows.
+                        divisor => self.wrapping_rem(divisor) == 0,
+                    }
+                }
+
+                fn is_even(self) -> bool {
+                    self % 2 == 0
+                }
+
+                fn is_power_of_two(self) -> bool {
+                    self > 0 && self.cast_unsigned().is_power_of_two()
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! unsigned {
+    ($($int:ty),* $(,)?) => {
+        $(
+            impl sealed::Sealed for $int {}
+
+            impl Numeric for $int {
+                fn compare_to_zero(self) -> Option<Ordering> {
+                    Some(self.cmp(&0))
+                }
+            }
+
+            impl Integer for $int {
+                type Primitive = $int;
+
+                fn is_multiple_of(self, divisor: $int) -> bool {
+                    <$int>::is_multiple_of(self, divisor)
+                }
+
+                fn is_even(self) -> bool {
+                    self % 2 == 0
+                }
+
+                fn is_power_of_two(self) -> bool {
+                    <$int>::is_power_of_two(self)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! float {
+    ($($float:ty),* $(,)?) => {
+        $(
+            impl sealed::Sealed for $float {}
+
+            impl Numeric for $float {
+                fn compare_to_zero(self) -> Option<Ordering> {
+                    self.partial_cmp(&0.0)
+                }
+            }
+        )*
+    };
+}
+
+signed!(i8, i16, i32, i64, i128, isize);
+unsigned!(u8, u16, u32, u64, u128, usize);
+float!(f32, f64);
+
+/// [NonZero] integers, like the integers inside.
+macro_rules! non_zero {
+    ($($int:ty),* $(,)?) => {
+        $(
+            impl sealed::Sealed for NonZero<$int> {}
+
+            impl Numeric for NonZero<$int> {
+                fn compare_to_zero(self) -> Option<Ordering> {
+                    self.get().compare_to_zero()
+                }
+            }
+
+            impl Integer for NonZero<$int> {
+                type Primitive = $int;
+
+                fn is_multiple_of(self, divisor: $int) -> bool {
+                    Integer::is_multiple_of(self.get(), divisor)
+                }
+
+                fn is_even(self) -> bool {
+                    self.get().is_even()
+                }
+
+                fn is_power_of_two(self) -> bool {
+                    Integer::is_power_of_two(self.get())
+                }
+            }
+        )*
+    };
+}
+
+non_zero!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+
+/// [Wrapping] integers, like the integers inside.
+impl<T: Integer> sealed::Sealed for Wrapping<T> {}
+
+impl<T: Integer> Numeric for Wrapping<T> {
+    fn compare_to_zero(self) -> Option<Ordering> {
+        self.0.compare_to_zero()
+    }
+}
+
+impl<T: Integer> Integer for Wrapping<T> {
+    type Primitive = T::Primitive;
+
+    fn is_multiple_of(self, divisor: T::Primitive) -> bool {
+        self.0.is_multiple_of(divisor)
+    }
+
+    fn is_even(self) -> bool {
+        self.0.is_even()
+    }
+
+    fn is_power_of_two(self) -> bool {
+        self.0.is_power_of_two()
+    }
+}
+
+impl<T: Numeric> Assert<T> {
+    /// Assert that `self` is greater than zero.
+    ///
+    /// ```
+    /// # use assert4rs::Assert;
+    /// Assert::that(3).is_positive();
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use assert4rs::Assert;
+    /// // Assertion failed: `(actual > 0)`
+    /// //   Actual: `0`
+    /// Assert::that(0).is_positive();
+    /// ```
+    #[track_caller]
+    pub fn is_positive(self) -> Self {
+        self.compares_to_zero("actual > 0", Ordering::Greater)
+    }
+
+    /// Assert that `self` is less than zero.
+    ///
+    /// ```
+    /// # use assert4rs::Assert;
+    /// Assert::that(-3.5).is_negative();
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use assert4rs::Assert;
+    /// Assert::that(-0.0).is_negative();
+    /// ```
+    #[track_caller]
+    pub fn is_negative(self) -> Self {
+        self.compares_to_zero("actual < 0", Ordering::Less)
+    }
+
+    /// Assert that `self` is zero, of either sign for floats.
+    ///
+    /// ```
+    /// # use assert4rs::Assert;
+    /// Assert::that(0u8).is_zero();
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use assert4rs::Assert;
+    /// Assert::that(1e-300).is_zero();
+    /// ```
+    #[track_caller]
+    pub fn is_zero(self) -> Self {
+        self.has_property("actual == 0", |actual| {
+            actual.compare_to_zero() == Some(Ordering::Equal)
+        })
+    }
+
+    /// Assert that `self` isn't zero. NaN isn't.
+    ///
+    /// ```
+    /// # use assert4rs::Assert;
+    /// Assert::that(-1).is_not_zero();
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use assert4rs::Assert;
+    /// Assert::that(0).is_not_zero();
+    /// ```
+    #[track_caller]
+    pub fn is_not_zero(self) -> Self {
+        self.has_property("actual != 0", |actual| {
+            actual.compare_to_zero() != Some(Ordering::Equal)
+        })
+    }
+
+    /// Fails `assertion` unless `self` compares to zero as `expected`.
+    /// NaN fails it whether negated or not.
+    #[track_caller]
+    fn compares_to_zero(mut self, assertion: &str, expected: Ordering) -> Self {
+        let negated = self.take_negation();
+        let ordering = self.actual.compare_to_zero();
+        if ordering.is_some_and(|ordering| (ordering == expected) != negated) {
+            return self;
+        }
+        let error = self
+            .error(assertion)
+            .negated(negated)
+            .detail("Actual", format!("{:?}", self.actual));
+        match ordering {
+            Some(_) => self.fail(error),
+            None => self.fail(error.detail("Reason", INCOMPARABLE.to_string())),
+        }
+        self
+    }
+
+    /// Fails `assertion` unless `property` holds for `self`.
+    #[track_caller]
+    pub(crate) fn has_property(
+        mut self,
+        assertion: &str,
+        property: impl FnOnce(T) -> bool,
+    ) -> Self {
+        let negated = self.take_negation();
+        if property(self.actual) == negated {
+            self.fail(
+                self.error(assertion)
+                    .negated(negated)
+                    .detail("Actual", format!("{:?}", self.actual)),
+            );
+        }
+        self
+    }
+}
+
+impl<T: Integer> Assert<T> {
+    /// Assert that `self` is even.
+    ///
+    /// ```
+    /// # use assert4rs::Assert;
+    /// Assert::that(-4).is_even();
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use assert4rs::Assert;
+    /// // Assertion failed: `(actual % 2 == 0)`
+    /// //   Actual: `3`
+    /// Assert::that(3).is_even();
+    /// ```
+    #[track_caller]
+    pub fn is_even(self) -> Self {
+        self.has_property("actual % 2 == 0", Integer::is_even)
+    }
+
+    /// Assert that `self` is odd.
+    ///
+    /// ```
+    /// # use assert4rs::Assert;
+    /// Assert::that(-3).is_odd();
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use assert4rs::Assert;
+    /// Assert::that(4).is_odd();
+    /// ```
+    #[track_caller]
+    pub fn is_odd(self) -> Self {
+        self.has_property("actual % 2 != 0", |actual| !actual.is_even())
+    }
+
+    /// Assert that `self` is a multiple of `divisor`. Only zero is a
+    /// multiple of zero.
+    ///
+    /// ```
+    /// # use assert4rs::Assert;
+    /// Assert::that(12).is_divisible_by(3);
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use assert4rs::Assert;
+    /// // Assertion failed: `(actual % divisor == 0)`
+    /// //   Actual:  `12`
+    /// //   Divisor: `5`
+    /// Assert::that(12).is_divisible_by(5);
+    /// ```
+    #[track_caller]
+    pub fn is_divisible_by(mut self, divisor: T::Primitive) -> Self {
+        let negated = self.take_negation();
+        if self.actual.is_multiple_of(divisor) == negated {
+            self.fail(
+                self.error("actual % divisor == 0")
+                    .negated(negated)
+                    .aligned_detail("Actual", format!("{:?}", self.actual), "Divisor")
+                    .detail("Divisor", format!("{divisor:?}")),
+            );
+        }
+        self
+    }
+
+    /// Assert that `self` is a positive power of two.
+    ///
+    /// ```
+    /// # use assert4rs::Assert;
+    /// Assert::that(64u32).is_power_of_two();
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use assert4rs::Assert;
+    /// Assert::that(-8).is_power_of_two();
+    /// ```
+    #[track_caller]
+    pub fn is_power_of_two(self) -> Self {
+        self.has_property("actual.is_power_of_two()", Integer::is_power_of_two)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Assert;
+    use std::num::{NonZero, Wrapping};
+
+    #[test]
+    fn sign_and_zero_apply_to_every_numeric_type() {
+        Assert::that(3i8).is_positive().is_not_zero();
+        Assert::that(-3isize).is_negative();
+        Assert::that(0u128).is_zero().not().is_positive();
+        Assert::that(-0.0f32).is_zero().not().is_negative();
+        Assert::that(NonZero::new(-5i32).unwrap()).is_negative();
+        Assert::that(Wrapping(u8::MAX) + Wrapping(1)).is_zero();
+    }
+
+    #[test]
+    fn divisibility_applies_to_every_integer_type() {
+        Assert::that(NonZero::new(12u64).unwrap())
+            .is_even()
+            .is_divisible_by(4)
+            .not()
+            .is_power_of_two();
+        Assert::that(Wrapping(7i16))
+            .is_odd()
+            .not()
+            .is_divisible_by(2);
+        Assert::that(i32::MIN)
+            .is_divisible_by(-1)
+            .not()
+            .is_power_of_two();
+        Assert::that(0).is_divisible_by(0);
+        Assert::that(1).not().is_divisible_by(0).is_power_of_two();
+    }
+
+    #[test]
+    #[should_panic(expected = "Assertion failed: `(actual > 0)`\n  Actual: `-1`")]
+    fn is_positive_reports_full_message() {
+        Assert::that(-1).is_positive();
+    }
+
+    #[test]
+    #[should_panic(expected = "Assertion failed: `!(actual == 0)`\n  Actual: `0.0`")]
+    fn not_is_zero_reports_full_message() {
+        Assert::that(0.0).not().is_zero();
+    }
+
+    #[test]
+    #[should_panic(expected = "Assertion failed: `(actual % 2 != 0)`\n  Actual: `4`")]
+    fn is_odd_reports_full_message() {
+        Assert::that(Wrapping(4)).is_odd();
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Assertion failed: `!(actual % divisor == 0)`\n  Actual:  `12`\n  Divisor: `3`"
+    )]
+    fn not_is_divisible_by_reports_full_message() {
+        Assert::that(12).not().is_divisible_by(3);
+    }
+
+    #[test]
+    fn nan_fails_sign_checks_either_way() {
+        let reason = "values are not comparable (partial_cmp returned None)";
+        let error = Assert::check(f64::NAN, |a| a.is_positive()).err().unwrap();
+        assert_eq!(error.details()[1].value(), reason);
+        let error = Assert::check(f64::NAN, |a| a.not().is_negative())
+            .err()
+            .unwrap();
+        assert_eq!(error.header(), "Assertion failed: `!(actual < 0)`");
+        assert_eq!(error.details()[1].value(), reason);
+        Assert::that(f64::NAN).is_not_zero();
+    }
+}
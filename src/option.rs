@@ -41,7 +41,8 @@ impl<T> Assert<Option<T>> {
         self.is(None)
     }
 
-    /// Unwrap the [Option] value, panic for [None].
+    /// Unwrap the [Option] value, panic for [None]. Appends `.unwrap()`
+    /// to the label path.
     ///
     /// ```
     /// # use assert4rs::Assert;
@@ -54,10 +55,14 @@ impl<T> Assert<Option<T>> {
     /// ```
     #[track_caller]
     pub fn unwrap(self) -> Assert<T> {
-        match self.actual {
-            Some(value) => Assert::that(value),
-            None => panic!("{}\n  Actual:   `None`", self.header("actual.is_some()")),
+        if self.actual.is_none() {
+            self.abort(self.error("actual.is_some()").aligned_detail(
+                "Actual",
+                String::from("None"),
+                "Expected",
+            ));
         }
+        self.map_suffixed(".unwrap()", |actual| actual.unwrap())
     }
 }
 
@@ -0,0 +1,251 @@
+//! Runtime side of the power-assert style `check!` macro from the
+//! `assert4rs-macros` crate: the expansion records the value of every
+//! sub-expression, and a failed check renders them as a tree under the
+//! expression.
+
+use std::fmt::Debug;
+use std::panic::Location;
+
+use crate::{Assert, AssertionError, collector};
+
+/// The values of the sub-expressions of one `check!`, each with the
+/// column of the expression text it's shown under. Values are recorded
+/// by reference where they live until the check is decided, and only
+/// rendered if it fails.
+#[derive(Default)]
+pub struct Recorder<'a> {
+    values: Vec<(usize, Recorded<'a>)>,
+}
+
+impl<'a> Recorder<'a> {
+    /// Records the `value` of the sub-expression at `column`.
+    pub fn record(&mut self, column: usize, value: Recorded<'a>) {
+        self.values.push((column, value));
+    }
+
+    /// Takes over the values of `nested`, the recorder of a side of `&&`
+    /// or `||` whose values don't live until the check is decided,
+    /// rendering them.
+    pub fn adopt(&mut self, nested: Recorder<'_>) {
+        for (column, value) in nested.values {
+            self.values
+                .push((column, Recorded::Rendered(value.render())));
+        }
+    }
+
+    /// Reports the failed check of `expression` with the recorded values
+    /// of its sub-expressions, like a failed assertion: inside
+    /// [`Assert::soft`] or [`Assert::check`] it's collected, otherwise it
+    /// panics.
+    #[track_caller]
+    pub fn fail(self, expression: &str) {
+        let values = self
+            .values
+            .iter()
+            .map(|(column, value)| {
+                let value = value.render();
+                match crate::truncate::max_len() {
+                    Some(max_len) => (*column, crate::truncate::window(&value, 0, max_len)),
+                    None => (*column, value),
+                }
+            })
+            .collect();
+        let error = AssertionError::new(None, expression, Location::caller())
+            .with_diff(render_tree(expression, values));
+        let assert = Assert {
+            actual: (),
+            label: None,
+            collector: collector::current(),
+            negated: false,
+        };
+        assert.fail(error);
+    }
+}
+
+/// The recorded value of a sub-expression.
+pub enum Recorded<'a> {
+    /// A value that's still around when the check is decided.
+    Value(&'a dyn Debug),
+    /// A value that had to be rendered when it was recorded.
+    Rendered(String),
+    /// A value without a [Debug] implementation.
+    Opaque,
+}
+
+impl Recorded<'_> {
+    fn render(&self) -> String {
+        match self {
+            Recorded::Value(value) => format!("{value:?}"),
+            Recorded::Rendered(rendering) => rendering.clone(),
+            Recorded::Opaque => String::from("<?>"),
+        }
+    }
+}
+
+/// Lays out `values` under `expression`, each hanging from a `|` at its
+/// column, packed into as few rows as possible without overlapping:
+///
+/// ```text
+///   a.len() == b.len() + 1
+///   |       |  |       | |
+///   3       |  1       2 1
+///           false
+/// ```
+fn render_tree(expression: &str, mut values: Vec<(usize, String)>) -> String {
+    // Placing values from right to left means a value only ever has to
+    // avoid the text and bars of values to its right.
+    values.sort_by_key(|&(column, _)| std::cmp::Reverse(column));
+    let mut bars: Vec<char> = Vec::new();
+    let mut rows: Vec<Vec<char>> = Vec::new();
+    for (column, value) in &values {
+        put(&mut bars, *column, "|");
+        let width = value.chars().count();
+        let free = |row: &Vec<char>| {
+            (*column..=column + width).all(|c| row.get(c).is_none_or(|&ch| ch == ' '))
+        };
+        let row = rows.iter().position(free).unwrap_or(rows.len());
+        if row == rows.len() {
+            rows.push(Vec::new());
+        }
+        for above in &mut rows[..row] {
+            put(above, *column, "|");
+        }
+        put(&mut rows[row], *column, value);
+    }
+    let mut tree = format!("  {expression}");
+    for line in std::iter::once(&bars).chain(&rows) {
+        let line: String = line.iter().collect();
+        tree.push_str(&format!("\n  {}", line.trim_end()));
+    }
+    tree
+}
+
+/// Writes `text` into `line` starting at `column`, padding with spaces.
+fn put(line: &mut Vec<char>, column: usize, text: &str) {
+    for (offset, c) in text.chars().enumerate() {
+        let at = column + offset;
+        if line.len() <= at {
+            line.resize(at + 1, ' ');
+        }
+        line[at] = c;
+    }
+}
+
+/// Borrows a sub-expression's value that stays in place until the check
+/// is decided, so it can be recorded with whichever of [DebugValue] or
+/// [NoDebugValue] applies.
+pub struct Wrap<'a, T: ?Sized>(pub &'a T);
+
+/// Borrows a sub-expression's value that's about to be moved, like an
+/// operand of `!` or `+`, so it can be recorded with whichever of
+/// [CopyValue], [DebugValue] or [NoDebugValue] applies.
+pub struct Moved<'a, T: ?Sized>(pub &'a T);
+
+/// Records moved values that implement [Debug] and [Copy] by reference,
+/// as moving them leaves them in place. Takes precedence over the others
+/// when called as `(&&&Moved(&value)).recorded()`.
+pub trait CopyValue<'a> {
+    fn recorded(&self) -> Recorded<'a>;
+}
+
+impl<'a, T: Debug + Copy> CopyValue<'a> for &&Moved<'a, T> {
+    fn recorded(&self) -> Recorded<'a> {
+        Recorded::Value(self.0)
+    }
+}
+
+/// Records values that implement [Debug]: by reference when called as
+/// `(&Wrap(&value)).recorded()`, and rendered right away when called as
+/// `(&&&Moved(&value)).recorded()`. Takes precedence over [NoDebugValue].
+pub trait DebugValue<'a> {
+    fn recorded(&self) -> Recorded<'a>;
+}
+
+impl<'a, T: Debug> DebugValue<'a> for Wrap<'a, T> {
+    fn recorded(&self) -> Recorded<'a> {
+        Recorded::Value(self.0)
+    }
+}
+
+impl<'a, T: Debug + ?Sized> DebugValue<'a> for &Moved<'_, T> {
+    fn recorded(&self) -> Recorded<'a> {
+        Recorded::Rendered(format!("{:?}", self.0))
+    }
+}
+
+/// Fallback for values without a [Debug] implementation.
+pub trait NoDebugValue<'a> {
+    fn recorded(&self) -> Recorded<'a>;
+}
+
+impl<'a, T: ?Sized> NoDebugValue<'a> for &Wrap<'_, T> {
+    fn recorded(&self) -> Recorded<'a> {
+        Recorded::Opaque
+    }
+}
+
+impl<'a, T: ?Sized> NoDebugValue<'a> for Moved<'_, T> {
+    fn recorded(&self) -> Recorded<'a> {
+        Recorded::Opaque
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn values_hang_under_their_columns() {
+        let values = vec![
+            (0, String::from("3")),
+            (11, String::from("1")),
+            (21, String::from("1")),
+            (19, String::from("2")),
+            (8, String::from("false")),
+        ];
+        assert_eq!(
+            render_tree("a.len() == b.len() + 1", values),
+            "  a.len() == b.len() + 1\
+             \n  |       |  |       | |\
+             \n  3       |  1       2 1\
+             \n          false"
+        );
+    }
+
+    #[test]
+    fn long_values_move_to_rows_below() {
+        let values = vec![(0, String::from("[1, 2, 3]")), (2, String::from("true"))];
+        assert_eq!(
+            render_tree("v == w", values),
+            "  v == w\n  | |\n  | true\n  [1, 2, 3]"
+        );
+    }
+
+    #[test]
+    // The borrows are what make the impls take precedence in turn.
+    #[allow(clippy::needless_borrow)]
+    fn values_are_recorded_by_reference_where_they_can_be() {
+        struct Opaque;
+        let (number, text) = (42, String::from("a"));
+        let recorded = [
+            (&Wrap(&text)).recorded(),
+            (&Wrap(&Opaque)).recorded(),
+            (&&&Moved(&number)).recorded(),
+            (&&&Moved(&text)).recorded(),
+            (&&&Moved(&Opaque)).recorded(),
+        ];
+        let kinds = recorded.iter().map(|value| match value {
+            Recorded::Value(_) => "value",
+            Recorded::Rendered(_) => "rendered",
+            Recorded::Opaque => "opaque",
+        });
+        assert_eq!(
+            kinds.collect::<Vec<_>>(),
+            ["value", "opaque", "value", "rendered", "opaque"]
+        );
+        assert_eq!(
+            recorded.iter().map(Recorded::render).collect::<Vec<_>>(),
+            ["\"a\"", "<?>", "42", "\"a\"", "<?>"]
+        );
+    }
+}
@@ -0,0 +1,245 @@
+//! Assertions that a value lies within, or outside, an interval given
+//! as any of Rust's range forms.
+
+use std::cmp::Ordering;
+use std::fmt::Debug;
+use std::ops::{Bound, RangeBounds};
+
+use crate::Assert;
+use crate::equals::INCOMPARABLE;
+
+impl<T> Assert<T>
+where
+    T: PartialOrd + Debug,
+{
+    /// Assert that `self` lies within `range`, which can be any of
+    /// `lo..hi`, `lo..=hi`, `lo..`, `..hi`, `..=hi`, `..` and
+    /// `(Bound, Bound)`. On failure, shows the interval and the bound
+    /// that was violated. Values not comparable to a bound, like NaN,
+    /// fail this and its negation alike.
+    ///
+    /// ```
+    /// # use assert4rs::Assert;
+    /// Assert::that(5).is_in(1..=10).is_in(5..).is_in(..6);
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use assert4rs::Assert;
+    /// // Assertion failed: `(actual.is_in(range))`
+    /// //   Actual:   `10`
+    /// //   Interval: `[1, 10)`
+    /// //   Violated: `upper bound, actual >= 10`
+    /// Assert::that(10).is_in(1..10);
+    /// ```
+    #[track_caller]
+    pub fn is_in(self, range: impl RangeBounds<T>) -> Self {
+        self.lies_in("actual.is_in(range)", bounds(&range), true)
+    }
+
+    /// Assert that `self` lies outside `range`, which can be any of the
+    /// forms [`Assert::is_in`] takes.
+    ///
+    /// ```
+    /// # use assert4rs::Assert;
+    /// Assert::that(0).is_not_in(1..=10);
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use assert4rs::Assert;
+    /// Assert::that(5).is_not_in(1..=10);
+    /// ```
+    #[track_caller]
+    pub fn is_not_in(self, range: impl RangeBounds<T>) -> Self {
+        self.lies_in("actual.is_not_in(range)", bounds(&range), false)
+    }
+
+    /// Assert that `self` lies between `lo` and `hi`, both included.
+    ///
+    /// ```
+    /// # use assert4rs::Assert;
+    /// Assert::that(10).is_between(1, 10);
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use assert4rs::Assert;
+    /// // Assertion failed: `(actual.is_between(lo, hi))`
+    /// //   Actual:   `0`
+    /// //   Interval: `[1, 10]`
+    /// //   Violated: `lower bound, actual < 1`
+    /// Assert::that(0).is_between(1, 10);
+    /// ```
+    #[track_caller]
+    pub fn is_between(self, lo: T, hi: T) -> Self {
+        let bounds = (Bound::Included(&lo), Bound::Included(&hi));
+        self.lies_in("actual.is_between(lo, hi)", bounds, true)
+    }
+
+    /// Fails `assertion` unless `self` lies within `bounds` if `inside`,
+    /// or outside them if not.
+    #[track_caller]
+    fn lies_in(mut self, assertion: &str, (lo, hi): (Bound<&T>, Bound<&T>), inside: bool) -> Self {
+        let negated = self.take_negation();
+        let violation = violation(&self.actual, lo, hi);
+        let incomparable = violation
+            .as_ref()
+            .is_some_and(|(_, comparable)| !comparable);
+        if !incomparable && violation.is_none() == (inside != negated) {
+            return self;
+        }
+        let mut error = self
+            .error(assertion)
+            .negated(negated)
+            .aligned_detail("Actual", format!("{:?}", self.actual), "Interval")
+            .detail("Interval", interval(lo, hi));
+        if let Some((violated, _)) = violation {
+            error = error.detail("Violated", violated);
+        }
+        self.fail(error);
+        self
+    }
+}
+
+fn bounds<T>(range: &impl RangeBounds<T>) -> (Bound<&T>, Bound<&T>) {
+    (range.start_bound(), range.end_bound())
+}
+
+/// The first bound of `lo` and `hi` that `actual` violates, and whether
+/// `actual` could be compared to it at all.
+fn violation<T: PartialOrd + Debug>(
+    actual: &T,
+    lo: Bound<&T>,
+    hi: Bound<&T>,
+) -> Option<(String, bool)> {
+    let check = |side: &str, bound: Bound<&T>, outside: Ordering| {
+        let (limit, included) = match bound {
+            Bound::Included(limit) => (limit, true),
+            Bound::Excluded(limit) => (limit, false),
+            Bound::Unbounded => return None,
+        };
+        let operator = match (outside, included) {
+            (Ordering::Less, true) => "<",
+            (Ordering::Less, false) => "<=",
+            (_, true) => ">",
+            (_, false) => ">=",
+        };
+        match actual.partial_cmp(limit) {
+            None => Some((format!("{side}, {INCOMPARABLE}"), false)),
+            Some(ordering) if ordering == outside || (!included && ordering.is_eq()) => {
+                Some((format!("{side}, actual {operator} {limit:?}"), true))
+            }
+            Some(_) => None,
+        }
+    };
+    check("lower bound", lo, Ordering::Less).or_else(|| check("upper bound", hi, Ordering::Greater))
+}
+
+/// Renders the interval between `lo` and `hi` in the usual notation,
+/// e.g. `[1, 10)` or `(-∞, 5]`.
+fn interval<T: Debug>(lo: Bound<&T>, hi: Bound<&T>) -> String {
+    let lo = match lo {
+        Bound::Included(lo) => format!("[{lo:?}"),
+        Bound::Excluded(lo) => format!("({lo:?}"),
+        Bound::Unbounded => String::from("(-∞"),
+    };
+    let hi = match hi {
+        Bound::Included(hi) => format!("{hi:?}]"),
+        Bound::Excluded(hi) => format!("{hi:?})"),
+        Bound::Unbounded => String::from("∞)"),
+    };
+    format!("{lo}, {hi}")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Assert;
+    use std::ops::Bound;
+
+    #[test]
+    fn is_in_accepts_every_range_form() {
+        Assert::that(5)
+            .is_in(1..10)
+            .is_in(1..=5)
+            .is_in(5..)
+            .is_in(..6)
+            .is_in(..=5)
+            .is_in(..)
+            .is_in((Bound::Excluded(4), Bound::Unbounded));
+        Assert::that("m").is_in("a".."z");
+    }
+
+    #[test]
+    #[should_panic(expected = "Assertion failed: `(actual.is_in(range))`\n  \
+                    Actual:   `0`\n  \
+                    Interval: `[1, 10]`\n  \
+                    Violated: `lower bound, actual < 1`")]
+    fn is_in_reports_violated_lower_bound() {
+        Assert::that(0).is_in(1..=10);
+    }
+
+    #[test]
+    fn is_in_reports_interval_and_bound_for_each_form() {
+        let violated = |range: (Bound<i32>, Bound<i32>)| {
+            let error = Assert::check(5, |a| a.is_in(range)).err().unwrap();
+            (
+                error.details()[1].value().to_string(),
+                error.details()[2].value().to_string(),
+            )
+        };
+        assert_eq!(
+            violated((Bound::Excluded(5), Bound::Unbounded)),
+            ("(5, ∞)".to_string(), "lower bound, actual <= 5".to_string())
+        );
+        assert_eq!(
+            violated((Bound::Unbounded, Bound::Included(4))),
+            ("(-∞, 4]".to_string(), "upper bound, actual > 4".to_string())
+        );
+        assert_eq!(
+            violated((Bound::Unbounded, Bound::Excluded(5))),
+            (
+                "(-∞, 5)".to_string(),
+                "upper bound, actual >= 5".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn is_not_in_reports_interval() {
+        let error = Assert::check(5, |a| a.is_not_in(1..=10)).err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "Assertion failed: `(actual.is_not_in(range))`\n  \
+             Actual:   `5`\n  \
+             Interval: `[1, 10]`"
+        );
+    }
+
+    #[test]
+    fn not_flips_range_assertions() {
+        Assert::that(0)
+            .not()
+            .is_in(1..=10)
+            .not()
+            .is_not_in(-1..1)
+            .not()
+            .is_between(1, 10);
+        let error = Assert::check(5, |a| a.not().is_in(1..=10)).err().unwrap();
+        assert_eq!(error.header(), "Assertion failed: `!(actual.is_in(range))`");
+    }
+
+    #[test]
+    fn incomparable_values_fail_either_way() {
+        let message = "lower bound, values are not comparable (partial_cmp returned None)";
+        let error = Assert::check(f64::NAN, |a| a.is_in(0.0..1.0))
+            .err()
+            .unwrap();
+        assert_eq!(error.details()[2].value(), message);
+        let error = Assert::check(f64::NAN, |a| a.is_not_in(0.0..1.0))
+            .err()
+            .unwrap();
+        assert_eq!(error.details()[2].value(), message);
+        let error = Assert::check(f64::NAN, |a| a.not().is_between(0.0, 1.0))
+            .err()
+            .unwrap();
+        assert_eq!(error.details()[2].value(), message);
+    }
+}
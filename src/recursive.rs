@@ -0,0 +1,351 @@
+//! Recursive comparisons: [Diff] comparisons that leave out some fields,
+//! ignore the order of some lists, or compare some types with a function
+//! of their own instead of `==`.
+
+use crate::Assert;
+use crate::structural_diff::{Comparison, Diff, Rules};
+
+/// A recursive comparison being set up, started with
+/// [`Assert::comparing_recursively`] and ended with
+/// [`RecursiveComparison::is`].
+pub struct RecursiveComparison<T> {
+    assert: Assert<T>,
+    rules: Rules,
+}
+
+impl<T> Assert<T> {
+    /// Start comparing `self` part by part with [Diff], like
+    /// [`Assert::is_eq_to`], but set up to leave out fields, ignore the
+    /// order of lists, or compare values of some types with a function of
+    /// their own. Fields are named by their path without indexes, keys and
+    /// the `.0` of options and results, e.g. `lines.qty`, and `*` stands for any number of fields, as in
+    /// `*.created_at`.
+    ///
+    /// ```
+    /// # use assert4rs::Assert;
+    /// Assert::that(vec![0.1 + 0.2, 1.0])
+    ///     .comparing_recursively()
+    ///     .with_comparator_for::<f64>(|a, b| (a - b).abs() < 1e-9)
+    ///     .is(vec![0.3, 1.0]);
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use assert4rs::Assert;
+    /// // Assertion failed: `(actual.comparing_recursively().is(expected))`
+    /// //   Actual:   `[0.30000000000000004, 1.5]`
+    /// //   Expected: `[0.3, 1.0]`
+    /// //   Differences:
+    /// //     [1]: 1.5 != 1.0
+    /// Assert::that(vec![0.1 + 0.2, 1.5])
+    ///     .comparing_recursively()
+    ///     .with_comparator_for::<f64>(|a, b| (a - b).abs() < 1e-9)
+    ///     .is(vec![0.3, 1.0]);
+    /// ```
+    pub fn comparing_recursively(self) -> RecursiveComparison<T> {
+        RecursiveComparison {
+            assert: self,
+            rules: Rules::default(),
+        }
+    }
+}
+
+impl<T> RecursiveComparison<T> {
+    /// Leave the given fields, e.g. `id`, `lines.sku` or `*.created_at`,
+    /// out of the comparison.
+    pub fn ignoring_fields<S: AsRef<str>>(mut self, fields: impl IntoIterator<Item = S>) -> Self {
+        let fields = fields.into_iter().map(|field| field.as_ref().to_string());
+        self.rules.ignored_fields.extend(fields);
+        self
+    }
+
+    /// Compare the lists in the given fields, e.g. `tags` or `*.tags`,
+    /// regardless of the order of their items. Use `*` for all lists.
+    pub fn ignoring_collection_order_in<S: AsRef<str>>(
+        mut self,
+        fields: impl IntoIterator<Item = S>,
+    ) -> Self {
+        let fields = fields.into_iter().map(|field| field.as_ref().to_string());
+        self.rules.unordered.extend(fields);
+        self
+    }
+
+    /// Compare values of type `U` with `compare` rather than with `==` or
+    /// part by part. Applies to types compared as a whole, like numbers
+    /// and [String], and to types deriving [Diff] without generics.
+    pub fn with_comparator_for<U: 'static>(
+        mut self,
+        compare: impl Fn(&U, &U) -> bool + 'static,
+    ) -> Self {
+        self.rules.compare_with(compare);
+        self
+    }
+}
+
+impl<T: Diff> RecursiveComparison<T> {
+    /// Assert that `self` equals `expected` as set up, listing the path
+    /// to every part that differs.
+    ///
+    /// ```
+    /// # use assert4rs::Assert;
+    /// Assert::that(vec!["b", "a"])
+    ///     .comparing_recursively()
+    ///     .ignoring_collection_order_in(["*"])
+    ///     .is(vec!["a", "b"]);
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use assert4rs::Assert;
+    /// // Assertion failed: `(actual.comparing_recursively().is(expected))`
+    /// //   Actual:   `["b", "c"]`
+    /// //   Expected: `["a", "b"]`
+    /// //   Ignored order in: `*`
+    /// //   Differences:
+    /// //     [1]: extra "c"
+    /// //     [0]: missing "a"
+    /// Assert::that(vec!["b", "c"])
+    ///     .comparing_recursively()
+    ///     .ignoring_collection_order_in(["*"])
+    ///     .is(vec!["a", "b"]);
+    /// ```
+    #[track_caller]
+    pub fn is(self, expected: T) -> Assert<T> {
+        let RecursiveComparison { mut assert, rules } = self;
+        let negated = assert.take_negation();
+        let ignored_fields = rules.ignored_fields.join("`, `");
+        let unordered = rules.unordered.join("`, `");
+        let changes = assert.actual.diff_with(&expected, &Comparison::new(rules));
+        if changes.is_empty() != negated {
+            return assert;
+        }
+        let mut error = assert.changes_error(
+            "actual.comparing_recursively().is(expected)",
+            &expected,
+            &changes,
+            negated,
+        );
+        if !ignored_fields.is_empty() {
+            error = error.detail("Ignored fields", ignored_fields);
+        }
+        if !unordered.is_empty() {
+            error = error.detail("Ignored order in", unordered);
+        }
+        assert.fail(error);
+        assert
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Change;
+    use std::collections::{BTreeMap, HashMap, HashSet};
+
+    #[derive(Debug, PartialEq)]
+    struct Tag {
+        id: u32,
+        name: String,
+    }
+
+    impl Diff for Tag {
+        fn diff_with(&self, other: &Self, comparison: &Comparison) -> Vec<Change> {
+            if let Some(changes) = comparison.custom(self, other) {
+                return changes;
+            }
+            let mut changes = comparison.diff_at(".id", &self.id, &other.id);
+            changes.extend(comparison.diff_at(".name", &self.name, &other.name));
+            changes
+        }
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Post {
+        id: u32,
+        score: f64,
+        tags: Vec<Tag>,
+        meta: BTreeMap<&'static str, Tag>,
+    }
+
+    impl Diff for Post {
+        fn diff_with(&self, other: &Self, comparison: &Comparison) -> Vec<Change> {
+            let mut changes = comparison.diff_at(".id", &self.id, &other.id);
+            changes.extend(comparison.diff_at(".score", &self.score, &other.score));
+            changes.extend(comparison.diff_at(".tags", &self.tags, &other.tags));
+            changes.extend(comparison.diff_at(".meta", &self.meta, &other.meta));
+            changes
+        }
+    }
+
+    fn tag(id: u32, name: &str) -> Tag {
+        Tag {
+            id,
+            name: name.to_string(),
+        }
+    }
+
+    fn post(id: u32, score: f64, tags: Vec<Tag>) -> Post {
+        Post {
+            id,
+            score,
+            tags,
+            meta: BTreeMap::from([("author", tag(id, "ann"))]),
+        }
+    }
+
+    fn differences(
+        actual: Post,
+        expected: Post,
+        set_up: impl Fn(RecursiveComparison<Post>) -> RecursiveComparison<Post>,
+    ) -> Option<String> {
+        Assert::check(actual, |a| set_up(a.comparing_recursively()).is(expected))
+            .err()
+            .map(|error| error.diff().unwrap_or_default().to_string())
+    }
+
+    #[test]
+    fn ignores_top_level_field_only() {
+        let actual = post(1, 1.0, vec![tag(1, "rust")]);
+        let expected = post(2, 1.0, vec![tag(2, "rust")]);
+        let found = differences(actual, expected, |c| c.ignoring_fields(["id"]));
+        assert_eq!(
+            found.as_deref(),
+            Some("  Differences:\n    .tags[0].id: 1 != 2\n    .meta[\"author\"].id: 1 != 2")
+        );
+    }
+
+    #[test]
+    fn ignores_field_at_any_depth_with_wildcard() {
+        let actual = post(1, 1.0, vec![tag(1, "rust")]);
+        let expected = post(2, 1.0, vec![tag(2, "rust")]);
+        assert_eq!(
+            differences(actual, expected, |c| c.ignoring_fields(["*.id"])),
+            None
+        );
+    }
+
+    #[test]
+    fn ignores_order_only_where_asked() {
+        let actual = post(1, 1.0, vec![tag(1, "a"), tag(1, "b")]);
+        let expected = post(1, 1.0, vec![tag(1, "b"), tag(1, "a")]);
+        assert_eq!(
+            differences(actual, expected, |c| c
+                .ignoring_collection_order_in(["tags"])),
+            None
+        );
+        let actual = post(1, 1.0, vec![tag(1, "a"), tag(1, "b")]);
+        let expected = post(1, 1.0, vec![tag(1, "b"), tag(1, "a")]);
+        assert_eq!(
+            differences(actual, expected, |c| c
+                .ignoring_collection_order_in(["meta"])),
+            Some(
+                "  Differences:\n    .tags[0].name: \"a\" != \"b\"\n    .tags[1].name: \"b\" != \"a\""
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn unordered_items_are_matched_ignoring_fields() {
+        let actual = post(1, 1.0, vec![tag(7, "a"), tag(8, "b")]);
+        let expected = post(1, 1.0, vec![tag(1, "b"), tag(2, "c")]);
+        let found = differences(actual, expected, |c| {
+            c.ignoring_fields(["tags.id"])
+                .ignoring_collection_order_in(["tags"])
+        });
+        assert_eq!(
+            found.as_deref(),
+            Some(
+                "  Differences:\n    \
+                 .tags[0]: extra Tag { id: 7, name: \"a\" }\n    \
+                 .tags[1]: missing Tag { id: 2, name: \"c\" }"
+            )
+        );
+    }
+
+    #[test]
+    fn uses_comparator_for_type() {
+        let actual = post(1, 0.1 + 0.2, vec![]);
+        let expected = post(1, 0.3, vec![]);
+        let approx = |a: &f64, b: &f64| (a - b).abs() < 1e-9;
+        assert_eq!(
+            differences(actual, expected, |c| c.with_comparator_for(approx)),
+            None
+        );
+    }
+
+    #[test]
+    fn comparator_replaces_field_by_field_comparison() {
+        let actual = post(1, 1.0, vec![tag(1, "Rust")]);
+        let expected = post(1, 1.0, vec![tag(2, "rust")]);
+        let same_name = |a: &Tag, b: &Tag| a.name.eq_ignore_ascii_case(&b.name);
+        assert_eq!(
+            differences(actual, expected, |c| c.with_comparator_for(same_name)),
+            None
+        );
+    }
+
+    #[test]
+    fn comparators_apply_to_set_elements() {
+        let same_name = |a: &String, b: &String| a.eq_ignore_ascii_case(b);
+        Assert::that(HashSet::from([String::from("Rust")]))
+            .comparing_recursively()
+            .with_comparator_for(same_name)
+            .is(HashSet::from([String::from("rust")]));
+    }
+
+    #[test]
+    fn comparators_apply_to_equal_map_values() {
+        let never = |_: &u32, _: &u32| false;
+        let error = Assert::check(HashMap::from([("a", 1_u32)]), |a| {
+            a.comparing_recursively()
+                .with_comparator_for(never)
+                .is(HashMap::from([("a", 1)]))
+        })
+        .err()
+        .unwrap();
+        assert_eq!(error.diff(), Some("  Differences:\n    [\"a\"]: 1 != 1"));
+    }
+
+    #[test]
+    fn reports_full_message() {
+        let error = Assert::check(vec![1, 2], |a| {
+            a.comparing_recursively()
+                .ignoring_fields(["id", "*.created_at"])
+                .ignoring_collection_order_in(["tags"])
+                .is(vec![1, 3])
+        })
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.header(),
+            "Assertion failed: `(actual.comparing_recursively().is(expected))`"
+        );
+        assert_eq!(
+            error.to_string().lines().skip(1).collect::<Vec<_>>(),
+            [
+                "  Actual:   `[1, 2]`",
+                "  Expected: `[1, 3]`",
+                "  Ignored fields: `id`, `*.created_at`",
+                "  Ignored order in: `tags`",
+                "  Differences:",
+                "    [1]: 2 != 3",
+            ]
+        );
+    }
+
+    #[test]
+    fn not_is_fails_when_equal_as_set_up() {
+        let error = Assert::check(vec!["b", "a"], |a| {
+            a.not()
+                .comparing_recursively()
+                .ignoring_collection_order_in(["*"])
+                .is(vec!["a", "b"])
+        })
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.header(),
+            "Assertion failed: `!(actual.comparing_recursively().is(expected))`"
+        );
+        assert_eq!(error.expected(), Some("not [\"a\", \"b\"]"));
+    }
+}
@@ -0,0 +1,405 @@
+//! Turns an [AssertionError] into the panic message of a failed
+//! assertion.
+//!
+//! The [Reporter] is chosen per thread with [set_reporter] (each test
+//! runs on its own thread), or else by the `ASSERT4RS_REPORTER`
+//! environment variable (`plain`, `ansi` or `json`), defaulting to
+//! [PlainReporter]. `ansi` falls back to plain text when
+//! [colors_enabled] says colors aren't wanted.
+
+use std::cell::RefCell;
+use std::io::IsTerminal;
+use std::rc::Rc;
+
+use crate::AssertionError;
+
+/// Environment variable selecting the [Reporter] for threads that didn't
+/// call [set_reporter].
+pub const REPORTER_ENV: &str = "ASSERT4RS_REPORTER";
+
+/// Renders failed assertions into panic messages.
+pub trait Reporter {
+    /// Renders a single failed assertion.
+    fn report(&self, error: &AssertionError) -> String;
+
+    /// Renders all failures of an [`Assert::soft`](crate::Assert::soft)
+    /// scope as one message. Defaults to a numbered list of [report]s,
+    /// each followed by its location.
+    ///
+    /// [report]: Reporter::report
+    fn report_all(&self, errors: &[AssertionError]) -> String {
+        let mut report = match errors.len() {
+            1 => String::from("1 soft assertion failed:"),
+            n => format!("{n} soft assertions failed:"),
+        };
+        for (number, error) in errors.iter().enumerate() {
+            report.push_str(&format!(
+                "\n\n{}) {}\n  at {}",
+                number + 1,
+                self.report(error),
+                error.location(),
+            ));
+        }
+        report
+    }
+}
+
+/// The plain-text format, e.g.
+///
+/// ```text
+/// Assertion failed for `user.age`: `(actual == expected)`
+///   Actual:   `25`
+///   Expected: `30`
+///              ^ differs at character 0 ('2' vs '3')
+/// ```
+pub struct PlainReporter;
+
+impl Reporter for PlainReporter {
+    fn report(&self, error: &AssertionError) -> String {
+        error.to_string()
+    }
+}
+
+/// The plain-text format with ANSI colors: a bold header, the actual
+/// value in red and the expected value in green, with the character at
+/// which they first differ highlighted. Removed and added lines of a
+/// line diff are red and green as well.
+///
+/// Used as-is when set with [set_reporter]; when selected through
+/// `ASSERT4RS_REPORTER=ansi` it only applies if [colors_enabled].
+pub struct AnsiReporter;
+
+const BOLD: &str = "\x1b[1m";
+const RED: &str = "\x1b[31m";
+const GREEN: &str = "\x1b[32m";
+const REVERSE: &str = "\x1b[7m";
+const RESET: &str = "\x1b[0m";
+
+impl Reporter for AnsiReporter {
+    fn report(&self, error: &AssertionError) -> String {
+        let difference = error
+            .actual()
+            .zip(error.expected())
+            .and_then(|(actual, expected)| crate::diff::first_difference(actual, expected));
+        let index = difference.as_ref().map(|d| d.index);
+        let mut report = format!("{BOLD}{}{RESET}", error.header());
+        for detail in error.details() {
+            let value = match detail.name() {
+                "Actual" => paint(detail.value(), RED, index),
+                "Expected" => paint(detail.value(), GREEN, index),
+                _ => detail.value().to_string(),
+            };
+            report.push_str(&format!("\n  {} `{value}`", detail.padded_name()));
+        }
+        if let Some(diff) = error.diff() {
+            report.push('\n');
+            report.push_str(&paint_diff(diff));
+        }
+        report
+    }
+}
+
+/// Paints the `-` lines of a line diff red and its `+` lines green.
+fn paint_diff(diff: &str) -> String {
+    diff.split('\n')
+        .map(|line| {
+            if line.starts_with("  -") {
+                format!("{RED}{line}{RESET}")
+            } else if line.starts_with("  +") {
+                format!("{GREEN}{line}{RESET}")
+            } else {
+                line.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Paints `value` in `color`, highlighting the character starting at
+/// byte `highlight` (if `value` has one there).
+fn paint(value: &str, color: &str, highlight: Option<usize>) -> String {
+    let Some((index, c)) = highlight.and_then(|i| Some((i, value.get(i..)?.chars().next()?)))
+    else {
+        return format!("{color}{value}{RESET}");
+    };
+    let (before, rest) = value.split_at(index);
+    let after = &rest[c.len_utf8()..];
+    format!("{color}{before}{REVERSE}{c}{RESET}{color}{after}{RESET}")
+}
+
+/// Whether colored output is wanted: `NO_COLOR` isn't set (see
+/// <https://no-color.org>) and stderr, where panic messages go, is a
+/// terminal.
+///
+/// ```
+/// use assert4rs::reporter::{self, AnsiReporter};
+///
+/// if reporter::colors_enabled() {
+///     reporter::set_reporter(AnsiReporter);
+/// }
+/// # reporter::reset_reporter();
+/// ```
+pub fn colors_enabled() -> bool {
+    let no_color = std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty());
+    !no_color && std::io::stderr().is_terminal()
+}
+
+/// A single-line JSON object per failure, for tools that parse test
+/// output:
+///
+/// ```text
+/// {"label":"user.age","assertion":"actual == expected","header":"…","actual":"25","expected":"30","details":[{"name":"Actual","value":"25"},…],"diff":"…","location":{"file":"tests/user.rs","line":4,"column":34}}
+/// ```
+///
+/// Absent fields are `null`. A soft scope's failures are reported as a
+/// JSON array of such objects.
+pub struct JsonReporter;
+
+impl Reporter for JsonReporter {
+    fn report(&self, error: &AssertionError) -> String {
+        let details: Vec<String> = error
+            .details()
+            .iter()
+            .map(|detail| {
+                format!(
+                    r#"{{"name":{},"value":{}}}"#,
+                    json_string(detail.name()),
+                    json_string(detail.value())
+                )
+            })
+            .collect();
+        let location = error.location();
+        format!(
+            r#"{{"label":{},"assertion":{},"header":{},"actual":{},"expected":{},"details":[{}],"diff":{},"location":{{"file":{},"line":{},"column":{}}}}}"#,
+            json_option(error.label()),
+            json_string(error.assertion()),
+            json_string(&error.header()),
+            json_option(error.actual()),
+            json_option(error.expected()),
+            details.join(","),
+            json_option(error.diff()),
+            json_string(location.file()),
+            location.line(),
+            location.column(),
+        )
+    }
+
+    fn report_all(&self, errors: &[AssertionError]) -> String {
+        let reports: Vec<String> = errors.iter().map(|error| self.report(error)).collect();
+        format!("[{}]", reports.join(","))
+    }
+}
+
+fn json_option(value: Option<&str>) -> String {
+    value.map_or_else(|| String::from("null"), json_string)
+}
+
+fn json_string(value: &str) -> String {
+    let mut json = String::with_capacity(value.len() + 2);
+    json.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c.is_control() => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+thread_local! {
+    static REPORTER: RefCell<Option<Rc<dyn Reporter>>> = const { RefCell::new(None) };
+}
+
+/// Use `reporter` for assertions failing on the current thread.
+///
+/// ```
+/// use assert4rs::Assert;
+/// use assert4rs::reporter::{self, JsonReporter};
+///
+/// reporter::set_reporter(JsonReporter);
+/// let panic = std::panic::catch_unwind(|| {
+///     Assert::that(1).is(2);
+/// })
+/// .unwrap_err();
+/// let message = panic.downcast_ref::<String>().unwrap();
+/// assert!(message.starts_with(r#"{"label":null,"#));
+/// # reporter::reset_reporter();
+/// ```
+pub fn set_reporter(reporter: impl Reporter + 'static) {
+    REPORTER.with(|current| *current.borrow_mut() = Some(Rc::new(reporter)));
+}
+
+/// Undo [set_reporter] for the current thread, falling back to the
+/// `ASSERT4RS_REPORTER` environment variable again.
+pub fn reset_reporter() {
+    REPORTER.with(|current| *current.borrow_mut() = None);
+}
+
+/// The reporter for the current thread.
+fn current() -> Rc<dyn Reporter> {
+    if let Some(reporter) = REPORTER.with(|current| current.borrow().clone()) {
+        return reporter;
+    }
+    match std::env::var(REPORTER_ENV).as_deref() {
+        Ok("ansi") if colors_enabled() => Rc::new(AnsiReporter),
+        Ok("json") => Rc::new(JsonReporter),
+        _ => Rc::new(PlainReporter),
+    }
+}
+
+/// Renders `error` with the current thread's reporter.
+pub(crate) fn report(error: &AssertionError) -> String {
+    current().report(error)
+}
+
+/// Renders a soft scope's `errors` with the current thread's reporter.
+pub(crate) fn report_all(errors: &[AssertionError]) -> String {
+    current().report_all(errors)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Assert;
+
+    fn error() -> AssertionError {
+        Assert::check(1, |a| a.named("x").is(2)).err().unwrap()
+    }
+
+    fn panic_message(f: impl FnOnce() + std::panic::UnwindSafe) -> String {
+        let message = std::panic::catch_unwind(f).unwrap_err();
+        message.downcast_ref::<String>().unwrap().clone()
+    }
+
+    #[test]
+    fn plain_reporter_matches_display() {
+        let error = error();
+        assert_eq!(PlainReporter.report(&error), error.to_string());
+    }
+
+    #[test]
+    fn ansi_reporter_colors_header_actual_and_expected() {
+        let report = AnsiReporter.report(&error());
+        assert!(
+            report.starts_with("\x1b[1mAssertion failed for `x`: `(actual == expected)`\x1b[0m"),
+            "report: {report:?}"
+        );
+        assert!(
+            report.contains("\n  Actual:   `\x1b[31m\x1b[7m1\x1b[0m\x1b[31m\x1b[0m`"),
+            "report: {report:?}"
+        );
+        assert!(
+            report.contains("\n  Expected: `\x1b[32m\x1b[7m2\x1b[0m\x1b[32m\x1b[0m`"),
+            "report: {report:?}"
+        );
+    }
+
+    #[test]
+    fn paint_highlights_differing_character() {
+        assert_eq!(
+            paint("hello wprld", RED, Some(7)),
+            "\x1b[31mhello w\x1b[7mp\x1b[0m\x1b[31mrld\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn paint_without_character_at_difference_colors_whole_value() {
+        assert_eq!(paint("ab", GREEN, Some(2)), "\x1b[32mab\x1b[0m");
+        assert_eq!(paint("ab", GREEN, None), "\x1b[32mab\x1b[0m");
+    }
+
+    #[test]
+    fn paint_diff_colors_removed_and_added_lines() {
+        assert_eq!(
+            paint_diff("  @@ -1,2 +1,2 @@\n   a\n  -b\n  +c"),
+            "  @@ -1,2 +1,2 @@\n   a\n\x1b[31m  -b\x1b[0m\n\x1b[32m  +c\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn paint_diff_leaves_pointer_alone() {
+        let pointer = "             ^ differs at character 0 ('1' vs '2')";
+        assert_eq!(paint_diff(pointer), pointer);
+    }
+
+    #[test]
+    fn paint_highlights_multibyte_character() {
+        assert_eq!(
+            paint("aée", RED, Some(1)),
+            "\x1b[31ma\x1b[7mé\x1b[0m\x1b[31me\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn json_reporter_renders_all_fields() {
+        let error = error();
+        let line = error.location().line();
+        let column = error.location().column();
+        assert_eq!(
+            JsonReporter.report(&error),
+            format!(
+                r#"{{"label":"x","assertion":"actual == expected","header":"Assertion failed for `x`: `(actual == expected)`","actual":"1","expected":"2","details":[{{"name":"Actual","value":"1"}},{{"name":"Expected","value":"2"}}],"diff":"             ^ differs at character 0 ('1' vs '2')","location":{{"file":"src/reporter.rs","line":{line},"column":{column}}}}}"#
+            )
+        );
+    }
+
+    #[test]
+    fn json_reporter_escapes_strings() {
+        assert_eq!(json_string("a\"b\\c\nd\u{1}"), r#""a\"b\\c\nd\u0001""#);
+    }
+
+    #[test]
+    fn json_reporter_reports_soft_failures_as_array() {
+        let report = JsonReporter.report_all(&[error(), error()]);
+        assert!(report.starts_with(r#"[{"label":"x","#), "report: {report}");
+        assert!(report.contains(r#"}},{"label":"x","#), "report: {report}");
+        assert!(report.ends_with("}}]"), "report: {report}");
+    }
+
+    #[test]
+    fn set_reporter_applies_to_panics_on_this_thread() {
+        set_reporter(JsonReporter);
+        let message = panic_message(|| {
+            Assert::that(1).is(2);
+        });
+        reset_reporter();
+        assert!(
+            message.starts_with(r#"{"label":null,"#),
+            "message: {message}"
+        );
+    }
+
+    #[test]
+    fn set_reporter_applies_to_soft_reports() {
+        set_reporter(JsonReporter);
+        let message = panic_message(|| {
+            Assert::soft(|s| {
+                s.that(1).is(2);
+            })
+        });
+        reset_reporter();
+        assert!(
+            message.starts_with(r#"[{"label":null,"#),
+            "message: {message}"
+        );
+    }
+
+    #[test]
+    fn reset_reporter_restores_plain_format() {
+        set_reporter(JsonReporter);
+        reset_reporter();
+        let message = panic_message(|| {
+            Assert::that(1).is(2);
+        });
+        assert!(
+            message.starts_with("Assertion failed: `(actual == expected)`"),
+            "message: {message}"
+        );
+    }
+}
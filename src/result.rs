@@ -17,18 +17,23 @@ impl<T, E> Assert<Result<T, E>> {
     /// Assert::that(result).is_ok();
     /// ```
     #[track_caller]
-    pub fn is_ok(self) -> Self
+    pub fn is_ok(mut self) -> Self
     where
         E: Debug,
     {
-        match &self.actual {
-            Ok(_) => self,
-            Err(error) => panic!(
-                "{}\n  Actual:   `Err({:?})`",
-                self.header("actual.is_ok()"),
-                error
-            ),
+        let negated = self.take_negation();
+        if self.actual.is_ok() == negated {
+            let actual = match &self.actual {
+                Err(error) => format!("Err({error:?})"),
+                _ => String::from("Ok(_)"),
+            };
+            self.fail(
+                self.error("actual.is_ok()")
+                    .negated(negated)
+                    .aligned_detail("Actual", actual, "Expected"),
+            );
         }
+        self
     }
 
     /// Assert that `actual` is [Err].
@@ -45,21 +50,27 @@ impl<T, E> Assert<Result<T, E>> {
     /// Assert::that(result).is_err();
     /// ```
     #[track_caller]
-    pub fn is_err(self) -> Self
+    pub fn is_err(mut self) -> Self
     where
         T: Debug,
     {
-        match &self.actual {
-            Ok(value) => panic!(
-                "{}\n  Actual:   `Ok({:?})`",
-                self.header("actual.is_err()"),
-                value
-            ),
-            Err(_) => self,
+        let negated = self.take_negation();
+        if self.actual.is_err() == negated {
+            let actual = match &self.actual {
+                Ok(value) => format!("Ok({value:?})"),
+                _ => String::from("Err(_)"),
+            };
+            self.fail(
+                self.error("actual.is_err()")
+                    .negated(negated)
+                    .aligned_detail("Actual", actual, "Expected"),
+            );
         }
+        self
     }
 
-    /// Unwrap the [Ok] value, panic for [Err].
+    /// Unwrap the [Ok] value, panic for [Err]. Appends `.unwrap()` to
+    /// the label path.
     ///
     /// ```
     /// # use assert4rs::Assert;
@@ -77,14 +88,18 @@ impl<T, E> Assert<Result<T, E>> {
     where
         E: Debug,
     {
-        let header = self.header("actual.is_ok()");
-        match self.actual {
-            Ok(value) => Assert::that(value),
-            Err(error) => panic!("{header}\n  Actual:   `Err({error:?})`"),
+        if let Err(error) = &self.actual {
+            self.abort(self.error("actual.is_ok()").aligned_detail(
+                "Actual",
+                format!("Err({error:?})"),
+                "Expected",
+            ));
         }
+        self.map_suffixed(".unwrap()", |actual| actual.unwrap())
     }
 
-    /// Unwrap the [Err] value, panic for [Ok].
+    /// Unwrap the [Err] value, panic for [Ok]. Appends `.unwrap_err()`
+    /// to the label path.
     ///
     /// ```
     /// # use assert4rs::Assert;
@@ -102,11 +117,14 @@ impl<T, E> Assert<Result<T, E>> {
     where
         T: Debug,
     {
-        let header = self.header("actual.is_err()");
-        match self.actual {
-            Ok(value) => panic!("{header}\n  Actual:   `Ok({value:?})`"),
-            Err(error) => Assert::that(error),
+        if let Ok(value) = &self.actual {
+            self.abort(self.error("actual.is_err()").aligned_detail(
+                "Actual",
+                format!("Ok({value:?})"),
+                "Expected",
+            ));
         }
+        self.map_suffixed(".unwrap_err()", |actual| actual.unwrap_err())
     }
 }
 
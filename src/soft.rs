@@ -0,0 +1,164 @@
+//! Soft assertions: collect every failure in a scope and report them
+//! together, instead of panicking on the first one.
+
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Arc;
+
+use crate::Assert;
+use crate::collector::{self, Collector};
+
+/// Handle passed to the closure of [`Assert::soft`], used to start soft
+/// assertion chains.
+pub struct SoftAssertions {
+    collector: Arc<Collector>,
+}
+
+impl SoftAssertions {
+    /// Create an [Assert] instance for the `actual` value whose failures
+    /// are collected instead of panicking right away.
+    pub fn that<T>(&self, actual: T) -> Assert<T> {
+        Assert {
+            actual,
+            label: None,
+            collector: Some(self.collector.clone()),
+            negated: false,
+        }
+    }
+}
+
+impl Assert<()> {
+    /// Run `f` in soft mode: every assertion started with
+    /// [SoftAssertions::that] records its failure and carries on, and
+    /// once `f` returns, all failures are reported together in a single
+    /// panic.
+    ///
+    /// ```
+    /// # use assert4rs::Assert;
+    /// Assert::soft(|s| {
+    ///     s.that(1).is(1);
+    ///     s.that(vec![1, 2, 3]).contains(&2);
+    /// });
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use assert4rs::Assert;
+    /// Assert::soft(|s| {
+    ///     s.that(1).is(2);
+    ///     s.that(vec![1, 2, 3]).contains(&9);
+    /// });
+    /// ```
+    ///
+    /// Assertions that can't continue after failing, such as `unwrap` on
+    /// [None], end the scope early; the report then includes everything
+    /// collected up to and including that failure.
+    #[track_caller]
+    pub fn soft(f: impl FnOnce(&SoftAssertions)) {
+        let soft = SoftAssertions {
+            collector: Arc::new(Collector::default()),
+        };
+        if let Err(payload) = panic::catch_unwind(AssertUnwindSafe(|| {
+            collector::in_scope(&soft.collector, || f(&soft))
+        })) && !collector::is_abort(&*payload)
+        {
+            panic::resume_unwind(payload);
+        }
+        let failures = soft.collector.take();
+        if !failures.is_empty() {
+            panic!("{}", crate::reporter::report_all(&failures));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Assert;
+
+    fn soft_report(f: impl FnOnce(&super::SoftAssertions)) -> String {
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| Assert::soft(f)));
+        let message = result.unwrap_err();
+        message.downcast_ref::<String>().unwrap().clone()
+    }
+
+    #[test]
+    fn passing_scope_does_not_panic() {
+        Assert::soft(|s| {
+            s.that(1).is(1).is_gt(0);
+            s.that(vec![1, 2, 3]).contains(&2).has_length(3);
+        });
+    }
+
+    #[test]
+    fn reports_every_failure_numbered() {
+        let report = soft_report(|s| {
+            s.that(1).named("a").is(2);
+            s.that(3).is(3);
+            s.that(vec![1, 2, 3]).named("b").contains(&9);
+        });
+        assert!(
+            report.starts_with("2 soft assertions failed:"),
+            "report: {report}"
+        );
+        assert!(
+            report.contains("\n\n1) Assertion failed for `a`: `(actual == expected)`"),
+            "report: {report}"
+        );
+        assert!(
+            report.contains("\n\n2) Assertion failed for `b`: `(actual.contains(expected))`"),
+            "report: {report}"
+        );
+    }
+
+    #[test]
+    fn reports_caller_location_of_each_failure() {
+        let line = line!() + 2;
+        let report = soft_report(|s| {
+            s.that(1).is(2);
+        });
+        assert!(
+            report.contains(&format!("  at {}:{line}:", file!())),
+            "report: {report}"
+        );
+    }
+
+    #[test]
+    fn chain_continues_after_failure() {
+        let report = soft_report(|s| {
+            s.that(1).is(2).is_gt(5);
+        });
+        assert!(report.contains("(actual == expected)"), "report: {report}");
+        assert!(report.contains("(actual > other)"), "report: {report}");
+    }
+
+    #[test]
+    fn map_keeps_soft_mode() {
+        let report = soft_report(|s| {
+            s.that("3").map(|v| v.len()).is(2);
+            s.that(1).is(3);
+        });
+        assert!(
+            report.starts_with("2 soft assertions failed:"),
+            "report: {report}"
+        );
+    }
+
+    #[test]
+    fn failed_unwrap_ends_scope_and_reports_collected_failures() {
+        let report = soft_report(|s| {
+            s.that(1).is(2);
+            s.that(None::<i32>).unwrap().is(1);
+            s.that(3).is(4);
+        });
+        assert!(
+            report.starts_with("2 soft assertions failed:"),
+            "report: {report}"
+        );
+        assert!(report.contains("(actual.is_some())"), "report: {report}");
+        assert!(!report.contains("`4`"), "report: {report}");
+    }
+
+    #[test]
+    #[should_panic(expected = "not an assertion")]
+    fn foreign_panics_propagate_unchanged() {
+        Assert::soft(|_| panic!("not an assertion"));
+    }
+}
@@ -1,4 +1,4 @@
-use crate::Assert;
+use crate::{Assert, AssertionError};
 
 /// DSL for [String].
 impl Assert<String> {
@@ -14,14 +14,18 @@ impl Assert<String> {
     /// Assert::that(String::from("hello world")).starts_with("world");
     /// ```
     #[track_caller]
-    pub fn starts_with(self, prefix: &str) -> Self {
-        assert!(
-            self.actual.starts_with(prefix),
-            "{}\n  Actual: `{:?}`\n  Prefix: `{:?}`",
-            self.header("actual.starts_with(prefix)"),
-            self.actual,
-            prefix,
-        );
+    pub fn starts_with(mut self, prefix: &str) -> Self {
+        let negated = self.take_negation();
+        if self.actual.starts_with(prefix) == negated {
+            self.fail(with_visible_whitespace(
+                self.error("actual.starts_with(prefix)")
+                    .negated(negated)
+                    .detail("Actual", format!("{:?}", self.actual))
+                    .detail("Prefix", format!("{prefix:?}")),
+                &self.actual,
+                ("Prefix", prefix),
+            ));
+        }
         self
     }
 
@@ -37,14 +41,18 @@ impl Assert<String> {
     /// Assert::that(String::from("hello world")).ends_with("hello");
     /// ```
     #[track_caller]
-    pub fn ends_with(self, suffix: &str) -> Self {
-        assert!(
-            self.actual.ends_with(suffix),
-            "{}\n  Actual: `{:?}`\n  Suffix: `{:?}`",
-            self.header("actual.ends_with(suffix)"),
-            self.actual,
-            suffix,
-        );
+    pub fn ends_with(mut self, suffix: &str) -> Self {
+        let negated = self.take_negation();
+        if self.actual.ends_with(suffix) == negated {
+            self.fail(with_visible_whitespace(
+                self.error("actual.ends_with(suffix)")
+                    .negated(negated)
+                    .detail("Actual", format!("{:?}", self.actual))
+                    .detail("Suffix", format!("{suffix:?}")),
+                &self.actual,
+                ("Suffix", suffix),
+            ));
+        }
         self
     }
 
@@ -60,14 +68,18 @@ impl Assert<String> {
     /// Assert::that(String::from("hello world")).contains("xyz");
     /// ```
     #[track_caller]
-    pub fn contains(self, pattern: &str) -> Self {
-        assert!(
-            self.actual.contains(pattern),
-            "{}\n  Actual:  `{:?}`\n  Pattern: `{:?}`",
-            self.header("actual.contains(pattern)"),
-            self.actual,
-            pattern,
-        );
+    pub fn contains(mut self, pattern: &str) -> Self {
+        let negated = self.take_negation();
+        if self.actual.contains(pattern) == negated {
+            self.fail(with_visible_whitespace(
+                self.error("actual.contains(pattern)")
+                    .negated(negated)
+                    .aligned_detail("Actual", format!("{:?}", self.actual), "Pattern")
+                    .detail("Pattern", format!("{pattern:?}")),
+                &self.actual,
+                ("Pattern", pattern),
+            ));
+        }
         self
     }
 }
@@ -90,14 +102,18 @@ impl Assert<&String> {
     /// Assert::that(&s).starts_with("world");
     /// ```
     #[track_caller]
-    pub fn starts_with(self, prefix: &str) -> Self {
-        assert!(
-            self.actual.starts_with(prefix),
-            "{}\n  Actual: `{:?}`\n  Prefix: `{:?}`",
-            self.header("actual.starts_with(prefix)"),
-            self.actual,
-            prefix,
-        );
+    pub fn starts_with(mut self, prefix: &str) -> Self {
+        let negated = self.take_negation();
+        if self.actual.starts_with(prefix) == negated {
+            self.fail(with_visible_whitespace(
+                self.error("actual.starts_with(prefix)")
+                    .negated(negated)
+                    .detail("Actual", format!("{:?}", self.actual))
+                    .detail("Prefix", format!("{prefix:?}")),
+                self.actual,
+                ("Prefix", prefix),
+            ));
+        }
         self
     }
 
@@ -115,14 +131,18 @@ impl Assert<&String> {
     /// Assert::that(&s).ends_with("hello");
     /// ```
     #[track_caller]
-    pub fn ends_with(self, suffix: &str) -> Self {
-        assert!(
-            self.actual.ends_with(suffix),
-            "{}\n  Actual: `{:?}`\n  Suffix: `{:?}`",
-            self.header("actual.ends_with(suffix)"),
-            self.actual,
-            suffix,
-        );
+    pub fn ends_with(mut self, suffix: &str) -> Self {
+        let negated = self.take_negation();
+        if self.actual.ends_with(suffix) == negated {
+            self.fail(with_visible_whitespace(
+                self.error("actual.ends_with(suffix)")
+                    .negated(negated)
+                    .detail("Actual", format!("{:?}", self.actual))
+                    .detail("Suffix", format!("{suffix:?}")),
+                self.actual,
+                ("Suffix", suffix),
+            ));
+        }
         self
     }
 
@@ -140,18 +160,52 @@ impl Assert<&String> {
     /// Assert::that(&s).contains("xyz");
     /// ```
     #[track_caller]
-    pub fn contains(self, pattern: &str) -> Self {
-        assert!(
-            self.actual.contains(pattern),
-            "{}\n  Actual:  `{:?}`\n  Pattern: `{:?}`",
-            self.header("actual.contains(pattern)"),
-            self.actual,
-            pattern,
-        );
+    pub fn contains(mut self, pattern: &str) -> Self {
+        let negated = self.take_negation();
+        if self.actual.contains(pattern) == negated {
+            self.fail(with_visible_whitespace(
+                self.error("actual.contains(pattern)")
+                    .negated(negated)
+                    .aligned_detail("Actual", format!("{:?}", self.actual), "Pattern")
+                    .detail("Pattern", format!("{pattern:?}")),
+                self.actual,
+                ("Pattern", pattern),
+            ));
+        }
         self
     }
 }
 
+/// Whether `text` has whitespace that is easy to miss or mistake in its
+/// `{:?}` rendering: leading, trailing or repeated whitespace, whitespace
+/// other than a plain space, or invisible characters.
+fn has_hidden_whitespace(text: &str) -> bool {
+    text.starts_with(char::is_whitespace)
+        || text.ends_with(char::is_whitespace)
+        || text.contains("  ")
+        || text
+            .chars()
+            .any(|c| (c.is_whitespace() && c != ' ') || crate::unicode::is_invisible(c))
+}
+
+/// Adds `Actual (visible)` and `<Name> (visible)` lines, rendering both
+/// strings with their whitespace and invisible characters marked, if
+/// either has some that's [easy to miss](has_hidden_whitespace).
+fn with_visible_whitespace(
+    error: AssertionError,
+    actual: &str,
+    (name, other): (&str, &str),
+) -> AssertionError {
+    if !has_hidden_whitespace(actual) && !has_hidden_whitespace(other) {
+        return error;
+    }
+    let actual_name = "Actual (visible)";
+    let other_name = format!("{name} (visible)");
+    error
+        .aligned_detail(actual_name, crate::diff::visible(actual), &other_name)
+        .aligned_detail(&other_name, crate::diff::visible(other), actual_name)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::Assert;
@@ -206,4 +260,49 @@ mod tests {
             .named("x")
             .ends_with("hello");
     }
+
+    #[test]
+    #[should_panic(
+        expected = "\n  Actual: `\"hello world \"`\n  Suffix: `\"world\"`\n  Actual (visible): `hello·world·`\n  Suffix (visible): `world`"
+    )]
+    fn ends_with_marks_trailing_whitespace() {
+        Assert::that(String::from("hello world ")).ends_with("world");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n  Actual (visible):  `a<U+00A0>b`\n  Pattern (visible): `a·b`")]
+    fn contains_shows_codepoints_of_invisible_characters() {
+        let s = String::from("a\u{a0}b");
+        Assert::that(&s).contains("a b");
+    }
+
+    #[test]
+    fn plain_spaces_are_not_marked() {
+        let result = std::panic::catch_unwind(|| {
+            Assert::that(String::from("hello world")).starts_with("world");
+        });
+        let message = result.unwrap_err();
+        let message = message.downcast_ref::<String>().unwrap();
+        assert!(!message.contains("(visible)"), "message: {message}");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Assertion failed: `!(actual.starts_with(prefix))`\n  Actual: `\"hello\"`\n  Prefix: `\"he\"`"
+    )]
+    fn not_starts_with_reports_full_message() {
+        Assert::that(String::from("hello")).not().starts_with("he");
+    }
+
+    #[test]
+    fn not_flips_string_assertions() {
+        let s = String::from("hello");
+        Assert::that(&s)
+            .not()
+            .starts_with("lo")
+            .not()
+            .ends_with("he")
+            .not()
+            .contains("xyz");
+    }
 }
@@ -1,61 +1,78 @@
-//! Structural (element/entry-aware) diff helpers for collection equality
-//! checks. Unlike `diff.rs` (which diffs `{:?}`-rendered text), these
-//! functions operate directly on typed collections, so they can name
-//! actual missing/extra/changed elements instead of a byte offset.
+//! Structural (field/element/entry-aware) diffs for [`Assert::is_eq_to`].
+//! Unlike `diff.rs` (which diffs `{:?}`-rendered text), these operate
+//! directly on typed values through the [Diff] trait, so they can name
+//! the path to every changed field and the actual missing/extra elements
+//! instead of a character offset.
 
-use std::collections::{HashMap, HashSet};
+use crate::Assert;
+use crate::approx::Tolerance;
+use crate::error::AssertionError;
+use std::any::{Any, TypeId};
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::fmt::{self, Debug, Display};
 use std::hash::Hash;
+use std::rc::Rc;
 
-/// Returns `(extra, missing)`: elements in `actual` but not `expected`,
-/// and elements in `expected` but not `actual`, matched by value
-/// (ignoring position). Deterministic without any bound beyond
-/// `PartialEq` — walks `actual`/`expected` in their own existing order.
-pub(crate) fn sequence_diff<'a, U: PartialEq>(
-    actual: &'a [U],
-    expected: &'a [U],
-) -> (Vec<&'a U>, Vec<&'a U>) {
-    let mut expected_remaining: Vec<&U> = expected.iter().collect();
+/// Returns `(extra, missing)`: the indexes of elements in `actual` but
+/// not `expected`, and of elements in `expected` but not `actual`,
+/// matched with `equal` (ignoring position). Deterministic without any
+/// bound on `U` — walks `actual`/`expected` in their own existing order.
+pub(crate) fn sequence_diff<U>(
+    actual: &[U],
+    expected: &[U],
+    equal: impl Fn(&U, &U) -> bool,
+) -> (Vec<usize>, Vec<usize>) {
+    let mut expected_remaining: Vec<usize> = (0..expected.len()).collect();
     let mut extra = Vec::new();
-    for a in actual {
-        if let Some(pos) = expected_remaining.iter().position(|e| **e == *a) {
+    for (i, a) in actual.iter().enumerate() {
+        if let Some(pos) = expected_remaining
+            .iter()
+            .position(|&j| equal(a, &expected[j]))
+        {
             expected_remaining.remove(pos);
         } else {
-            extra.push(a);
+            extra.push(i);
         }
     }
     (extra, expected_remaining)
 }
 
-/// Returns `(extra, missing)`: the symmetric difference between `actual`
-/// and `expected`, each side sorted for deterministic output (`HashSet`
+/// Returns `(extra, missing)`: elements in `actual` not matched in
+/// `expected` and elements in `expected` not matched in `actual`, matched
+/// with `equal`, each side sorted for deterministic output (`HashSet`
 /// iteration order is not itself deterministic).
-pub(crate) fn set_diff<'a, T: Eq + Hash + Ord>(
-    actual: &'a HashSet<T>,
-    expected: &'a HashSet<T>,
+pub(crate) fn set_diff<'a, T: Ord + 'a>(
+    actual: impl IntoIterator<Item = &'a T>,
+    expected: impl IntoIterator<Item = &'a T>,
+    equal: impl Fn(&T, &T) -> bool,
 ) -> (Vec<&'a T>, Vec<&'a T>) {
-    let mut extra: Vec<&T> = actual.difference(expected).collect();
-    let mut missing: Vec<&T> = expected.difference(actual).collect();
-    extra.sort();
-    missing.sort();
-    (extra, missing)
+    let mut actual: Vec<&T> = actual.into_iter().collect();
+    let mut expected: Vec<&T> = expected.into_iter().collect();
+    actual.sort();
+    expected.sort();
+    let (extra, missing) = sequence_diff(&actual, &expected, |a, e| equal(a, e));
+    (
+        extra.into_iter().map(|i| actual[i]).collect(),
+        missing.into_iter().map(|j| expected[j]).collect(),
+    )
 }
 
-/// Returns `(missing_keys, extra_keys, changed)`: keys `expected` has
+/// Returns `(missing_keys, extra_keys, shared)`: keys `expected` has
 /// that `actual` doesn't, keys `actual` has that `expected` doesn't, and
-/// keys present in both with different values (as `(key, actual_value,
+/// keys present in both with their values (as `(key, actual_value,
 /// expected_value)`). All three sorted by key for deterministic output.
 #[allow(clippy::type_complexity)]
-pub(crate) fn map_diff<'a, K: Eq + Hash + Ord, V: PartialEq>(
+pub(crate) fn map_diff<'a, K: Eq + Hash + Ord, V>(
     actual: &'a HashMap<K, V>,
     expected: &'a HashMap<K, V>,
 ) -> (Vec<&'a K>, Vec<&'a K>, Vec<(&'a K, &'a V, &'a V)>) {
     let mut missing_keys = Vec::new();
-    let mut changed = Vec::new();
+    let mut shared = Vec::new();
     for (k, ev) in expected {
         match actual.get(k) {
             None => missing_keys.push(k),
-            Some(av) if av != ev => changed.push((k, av, ev)),
-            _ => {}
+            Some(av) => shared.push((k, av, ev)),
         }
     }
     let mut extra_keys: Vec<&K> = actual
@@ -64,48 +81,829 @@ pub(crate) fn map_diff<'a, K: Eq + Hash + Ord, V: PartialEq>(
         .collect();
     missing_keys.sort();
     extra_keys.sort();
-    changed.sort_by_key(|(k, _, _)| *k);
-    (missing_keys, extra_keys, changed)
+    shared.sort_by_key(|(k, _, _)| *k);
+    (missing_keys, extra_keys, shared)
+}
+
+/// A part in which two values differ: where it is, and the `{:?}`
+/// renderings of both sides. A side is `None` when only the other value
+/// has the part, like an element missing from a list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Change {
+    path: String,
+    actual: Option<String>,
+    expected: Option<String>,
+    /// Says more about how the sides differ, e.g. by how much.
+    note: Option<String>,
+}
+
+impl Change {
+    /// The values differ as a whole.
+    pub fn changed(actual: &(impl Debug + ?Sized), expected: &(impl Debug + ?Sized)) -> Self {
+        Change::new(
+            String::new(),
+            Some(format!("{actual:?}")),
+            Some(format!("{expected:?}")),
+        )
+    }
+
+    /// Only the actual value has this part.
+    pub fn extra(actual: &(impl Debug + ?Sized)) -> Self {
+        Change::new(String::new(), Some(format!("{actual:?}")), None)
+    }
+
+    /// Only the expected value has this part.
+    pub fn missing(expected: &(impl Debug + ?Sized)) -> Self {
+        Change::new(String::new(), None, Some(format!("{expected:?}")))
+    }
+
+    pub(crate) fn new(path: String, actual: Option<String>, expected: Option<String>) -> Self {
+        Change {
+            path,
+            actual,
+            expected,
+            note: None,
+        }
+    }
+
+    /// Adds `note` on how the sides differ, shown after them in
+    /// parentheses.
+    pub(crate) fn noted(mut self, note: String) -> Self {
+        self.note = Some(note);
+        self
+    }
+
+    /// Moves the change into the part at `segment`, e.g. `.name` or `[3]`,
+    /// of the values compared.
+    pub fn at(mut self, segment: &str) -> Self {
+        self.path.insert_str(0, segment);
+        self
+    }
+
+    /// Where the part is, e.g. `.orders[3].qty`, or empty for the values
+    /// themselves.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// The actual value's rendering of the part, if it has one.
+    pub fn actual(&self) -> Option<&str> {
+        self.actual.as_deref()
+    }
+
+    /// The expected value's rendering of the part, if it has one.
+    pub fn expected(&self) -> Option<&str> {
+        self.expected.as_deref()
+    }
+
+    /// Whether the values differ as a whole rather than in some part.
+    pub(crate) fn is_whole(&self) -> bool {
+        self.path.is_empty() && self.actual.is_some() && self.expected.is_some()
+    }
+}
+
+/// Renders e.g. `.qty: 2 != 3`, `[4]: missing 7` or `[5]: extra 8`,
+/// followed by the note in parentheses if there is one.
+impl Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let shown = |value: &str| match crate::truncate::max_len() {
+            Some(max_len) => crate::truncate::window(value, 0, max_len),
+            None => value.to_string(),
+        };
+        if !self.path.is_empty() {
+            write!(f, "{}: ", self.path)?;
+        }
+        match (&self.actual, &self.expected) {
+            (Some(actual), Some(expected)) => write!(f, "{} != {}", shown(actual), shown(expected)),
+            (Some(actual), None) => write!(f, "extra {}", shown(actual)),
+            (None, Some(expected)) => write!(f, "missing {}", shown(expected)),
+            (None, None) => unreachable!("Change always has at least one side set"),
+        }?;
+        match &self.note {
+            Some(note) => write!(f, " ({note})"),
+            None => Ok(()),
+        }
+    }
+}
+
+/// At most this many changes are listed while truncation is on.
+const MAX_CHANGES: usize = 20;
+
+/// Lists `changes` under a `Differences:` heading.
+pub(crate) fn describe(changes: &[Change]) -> String {
+    let shown = match crate::truncate::max_len() {
+        Some(_) => changes.len().min(MAX_CHANGES),
+        None => changes.len(),
+    };
+    let mut block = String::from("  Differences:");
+    for change in &changes[..shown] {
+        block.push_str(&format!("\n    {change}"));
+    }
+    if shown < changes.len() {
+        block.push_str(&format!("\n    … {} more", changes.len() - shown));
+    }
+    block
+}
+
+/// How [Diff] compares values: which fields to leave out, which lists to
+/// compare regardless of order, and which types to compare with a
+/// function of their own rather than `==`. Set up with
+/// [`Assert::comparing_recursively`]; the default compares everything,
+/// in order, with `==`.
+///
+/// Fields are matched by their path without indexes, keys and the `.0`
+/// of `Some`, `Ok` and `Err`, the way [Change::path] shows it but with no
+/// leading dot, e.g. `lines.qty` for `.lines[0].qty`. A `*` segment stands for any number of fields, so
+/// `*.created_at` matches `created_at` at any depth.
+#[derive(Clone, Default)]
+pub struct Comparison {
+    rules: Rc<Rules>,
+    /// The fields leading to the part being compared.
+    fields: Vec<String>,
+}
+
+/// A function comparing two values of the type it's registered for.
+type Comparator = Box<dyn Fn(&dyn Any, &dyn Any) -> bool>;
+
+#[derive(Default)]
+pub(crate) struct Rules {
+    pub(crate) ignored_fields: Vec<String>,
+    pub(crate) unordered: Vec<String>,
+    pub(crate) comparators: Vec<(TypeId, Comparator)>,
+    /// How far apart floats may be, if they needn't be equal.
+    pub(crate) tolerance: Option<Tolerance>,
+}
+
+impl Rules {
+    /// Registers `compare` for values of type `U`, replacing any function
+    /// registered for it before.
+    pub(crate) fn compare_with<U: 'static>(&mut self, compare: impl Fn(&U, &U) -> bool + 'static) {
+        let comparator: Comparator = Box::new(move |actual, expected| {
+            match (actual.downcast_ref::<U>(), expected.downcast_ref::<U>()) {
+                (Some(actual), Some(expected)) => compare(actual, expected),
+                _ => unreachable!("comparators are only called for their own type"),
+            }
+        });
+        self.comparators.retain(|(id, _)| *id != TypeId::of::<U>());
+        self.comparators.push((TypeId::of::<U>(), comparator));
+    }
+}
+
+impl Comparison {
+    pub(crate) fn new(rules: Rules) -> Self {
+        Comparison {
+            rules: Rc::new(rules),
+            fields: Vec::new(),
+        }
+    }
+
+    /// The changes between the parts at `segment`, e.g. `.name`, `[3]` or
+    /// `["key"]`, of the values compared, moved into place. None if the
+    /// part is a field left out of the comparison.
+    pub fn diff_at<U: Diff + ?Sized>(
+        &self,
+        segment: &str,
+        actual: &U,
+        expected: &U,
+    ) -> Vec<Change> {
+        match self.part(segment) {
+            Some(part) => at(actual.diff_with(expected, &part), segment),
+            None => Vec::new(),
+        }
+    }
+
+    /// Whether the parts at `segment` are equal, like
+    /// [diff_at](Self::diff_at) finding no changes, but without rendering
+    /// any.
+    pub fn eq_at<U: Diff + ?Sized>(&self, segment: &str, actual: &U, expected: &U) -> bool {
+        self.part(segment)
+            .is_none_or(|part| actual.eq_with(expected, &part))
+    }
+
+    /// How to compare the part at `segment`, or `None` if it's a field
+    /// left out of the comparison.
+    fn part(&self, segment: &str) -> Option<Cow<'_, Comparison>> {
+        let Some(field) = segment.strip_prefix('.') else {
+            return Some(Cow::Borrowed(self));
+        };
+        let mut part = self.clone();
+        part.fields.push(field.to_string());
+        (!part.matches_any(&self.rules.ignored_fields)).then_some(Cow::Owned(part))
+    }
+
+    /// Compares values that can only differ as a whole, with the function
+    /// registered for their type if there is one, or else with `==`.
+    pub fn whole<U: Debug + PartialEq + 'static>(&self, actual: &U, expected: &U) -> Vec<Change> {
+        if self.whole_eq(actual, expected) {
+            Vec::new()
+        } else {
+            vec![Change::changed(actual, expected)]
+        }
+    }
+
+    /// Whether values compared as a [whole](Self::whole) are equal.
+    pub fn whole_eq<U: PartialEq + 'static>(&self, actual: &U, expected: &U) -> bool {
+        self.custom_eq(actual, expected)
+            .unwrap_or_else(|| actual == expected)
+    }
+
+    /// Compares floats with the function registered for their type if
+    /// there is one, or else within the tolerance if there is one, or
+    /// else with `==`.
+    fn float<U: Debug + PartialEq + Copy + Into<f64> + 'static>(
+        &self,
+        actual: &U,
+        expected: &U,
+    ) -> Vec<Change> {
+        if let Some(changes) = self.custom(actual, expected) {
+            return changes;
+        }
+        let Some(tolerance) = self.rules.tolerance else {
+            return self.whole(actual, expected);
+        };
+        match tolerance.excess((*actual).into(), (*expected).into()) {
+            Some(excess) => vec![Change::changed(actual, expected).noted(excess)],
+            None => Vec::new(),
+        }
+    }
+
+    /// Whether floats compared like [float](Self::float) are equal.
+    fn float_eq<U: PartialEq + Copy + Into<f64> + 'static>(
+        &self,
+        actual: &U,
+        expected: &U,
+    ) -> bool {
+        if let Some(equal) = self.custom_eq(actual, expected) {
+            return equal;
+        }
+        match self.rules.tolerance {
+            Some(tolerance) => tolerance
+                .excess((*actual).into(), (*expected).into())
+                .is_none(),
+            None => actual == expected,
+        }
+    }
+
+    /// Compares with the function registered for the values' type, if
+    /// there is one.
+    pub fn custom<U: Debug + 'static>(&self, actual: &U, expected: &U) -> Option<Vec<Change>> {
+        let equal = self.custom_eq(actual, expected)?;
+        Some(if equal {
+            Vec::new()
+        } else {
+            vec![Change::changed(actual, expected)]
+        })
+    }
+
+    /// Whether the function registered for the values' type, if there is
+    /// one, finds them equal.
+    pub fn custom_eq<U: 'static>(&self, actual: &U, expected: &U) -> Option<bool> {
+        let (_, compare) = self
+            .rules
+            .comparators
+            .iter()
+            .find(|(id, _)| *id == TypeId::of::<U>())?;
+        Some(compare(actual, expected))
+    }
+
+    /// Whether the order of the items in the list being compared doesn't
+    /// matter.
+    pub fn ignores_order(&self) -> bool {
+        self.matches_any(&self.rules.unordered)
+    }
+
+    fn matches_any(&self, patterns: &[String]) -> bool {
+        patterns.iter().any(|pattern| {
+            let pattern: Vec<&str> = pattern.split('.').collect();
+            matches(&pattern, &self.fields)
+        })
+    }
+}
+
+/// Whether the `fields` of a path match `pattern`, where `*` matches any
+/// number of fields.
+fn matches(pattern: &[&str], fields: &[String]) -> bool {
+    match pattern.split_first() {
+        None => fields.is_empty(),
+        Some((&"*", rest)) => (0..=fields.len()).any(|skipped| matches(rest, &fields[skipped..])),
+        Some((segment, rest)) => {
+            fields.first().is_some_and(|field| field == segment) && matches(rest, &fields[1..])
+        }
+    }
+}
+
+/// Compares two values of a type part by part, for [`Assert::is_eq_to`]
+/// and [recursive comparisons](Assert::comparing_recursively).
+///
+/// Derive it for structs and enums with `#[derive(Diff)]` (with the
+/// `macros` feature), or implement it by hand: compare each part with
+/// [Comparison::diff_at], which also moves the changes found into place.
+///
+/// ```
+/// use assert4rs::{Assert, Change, Comparison, Diff};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Line { sku: String, qty: u32 }
+///
+/// impl Diff for Line {
+///     fn diff_with(&self, other: &Self, comparison: &Comparison) -> Vec<Change> {
+///         let mut changes = comparison.diff_at(".sku", &self.sku, &other.sku);
+///         changes.extend(comparison.diff_at(".qty", &self.qty, &other.qty));
+///         changes
+///     }
+/// }
+///
+/// let line = |qty| Line { sku: String::from("apple"), qty };
+/// Assert::that(line(2).diff(&line(3))[0].to_string()).is(".qty: 2 != 3");
+/// ```
+pub trait Diff: Debug {
+    /// The parts in which `self`, the actual value, differs from
+    /// `other`, the expected one, as far as `comparison` is concerned.
+    /// Empty if they're equal.
+    fn diff_with(&self, other: &Self, comparison: &Comparison) -> Vec<Change>;
+
+    /// Whether `self` and `other` are equal as far as `comparison` is
+    /// concerned, like [diff_with](Self::diff_with) finding no changes.
+    /// Lists and sets match up their items with it, so override it when
+    /// you can tell without rendering the changes, comparing the parts
+    /// with [Comparison::eq_at].
+    fn eq_with(&self, other: &Self, comparison: &Comparison) -> bool {
+        self.diff_with(other, comparison).is_empty()
+    }
+
+    /// The parts in which `self`, the actual value, differs from
+    /// `other`, the expected one. Empty if they're equal.
+    fn diff(&self, other: &Self) -> Vec<Change> {
+        self.diff_with(other, &Comparison::default())
+    }
+}
+
+impl<T: Diff + ?Sized> Diff for &T {
+    fn diff_with(&self, other: &Self, comparison: &Comparison) -> Vec<Change> {
+        (**self).diff_with(*other, comparison)
+    }
+
+    fn eq_with(&self, other: &Self, comparison: &Comparison) -> bool {
+        (**self).eq_with(*other, comparison)
+    }
+}
+
+impl<T: Diff + ?Sized> Diff for Box<T> {
+    fn diff_with(&self, other: &Self, comparison: &Comparison) -> Vec<Change> {
+        (**self).diff_with(other, comparison)
+    }
+
+    fn eq_with(&self, other: &Self, comparison: &Comparison) -> bool {
+        (**self).eq_with(other, comparison)
+    }
+}
+
+crate::diff_as_whole!(
+    (),
+    bool,
+    char,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    String,
+);
+
+/// Compared within the tolerance of an
+/// [approximate comparison](Assert::is_approx_eq), if there is one.
+impl Diff for f32 {
+    fn diff_with(&self, other: &Self, comparison: &Comparison) -> Vec<Change> {
+        comparison.float(self, other)
+    }
+
+    fn eq_with(&self, other: &Self, comparison: &Comparison) -> bool {
+        comparison.float_eq(self, other)
+    }
+}
+
+/// Like the [f32] impl.
+impl Diff for f64 {
+    fn diff_with(&self, other: &Self, comparison: &Comparison) -> Vec<Change> {
+        comparison.float(self, other)
+    }
+
+    fn eq_with(&self, other: &Self, comparison: &Comparison) -> bool {
+        comparison.float_eq(self, other)
+    }
+}
+
+/// Compared with `==`: being unsized, `str` can't have a function of its
+/// own registered; register one for `String` instead.
+impl Diff for str {
+    fn diff_with(&self, other: &Self, _: &Comparison) -> Vec<Change> {
+        if self == other {
+            Vec::new()
+        } else {
+            vec![Change::changed(self, other)]
+        }
+    }
+
+    fn eq_with(&self, other: &Self, _: &Comparison) -> bool {
+        self == other
+    }
+}
+
+/// Compares the values inside, at `.0`, if both are `Some`. Field paths
+/// see through the option, so that `address.city` names the city of an
+/// `address: Option<Address>`.
+impl<T: Diff> Diff for Option<T> {
+    fn diff_with(&self, other: &Self, comparison: &Comparison) -> Vec<Change> {
+        match (self, other) {
+            (Some(actual), Some(expected)) => at(actual.diff_with(expected, comparison), ".0"),
+            (None, None) => Vec::new(),
+            _ => vec![Change::changed(self, other)],
+        }
+    }
+
+    fn eq_with(&self, other: &Self, comparison: &Comparison) -> bool {
+        match (self, other) {
+            (Some(actual), Some(expected)) => actual.eq_with(expected, comparison),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+}
+
+/// Compares the values inside, at `.0`, if both are `Ok` or both `Err`.
+/// Like the [Option] impl, field paths see through the result.
+impl<T: Diff, E: Diff> Diff for Result<T, E> {
+    fn diff_with(&self, other: &Self, comparison: &Comparison) -> Vec<Change> {
+        match (self, other) {
+            (Ok(actual), Ok(expected)) => at(actual.diff_with(expected, comparison), ".0"),
+            (Err(actual), Err(expected)) => at(actual.diff_with(expected, comparison), ".0"),
+            _ => vec![Change::changed(self, other)],
+        }
+    }
+
+    fn eq_with(&self, other: &Self, comparison: &Comparison) -> bool {
+        match (self, other) {
+            (Ok(actual), Ok(expected)) => actual.eq_with(expected, comparison),
+            (Err(actual), Err(expected)) => actual.eq_with(expected, comparison),
+            _ => false,
+        }
+    }
+}
+
+/// [Diff] for tuples, comparing their items at `.0`, `.1` and so on.
+macro_rules! diff_tuple {
+    ($(($($index:tt $item:ident),+)),* $(,)?) => {
+        $(
+            impl<$($item: Diff),+> Diff for ($($item,)+) {
+                fn diff_with(&self, other: &Self, comparison: &Comparison) -> Vec<Change> {
+                    let mut changes = Vec::new();
+                    $(
+                        changes.extend(comparison.diff_at(
+                            concat!(".", stringify!($index)),
+                            &self.$index,
+                            &other.$index,
+                        ));
+                    )+
+                    changes
+                }
+
+                fn eq_with(&self, other: &Self, comparison: &Comparison) -> bool {
+                    $(
+                        comparison.eq_at(
+                            concat!(".", stringify!($index)),
+                            &self.$index,
+                            &other.$index,
+                        )
+                    )&&+
+                }
+            }
+        )*
+    };
+}
+
+diff_tuple!(
+    (0 A),
+    (0 A, 1 B),
+    (0 A, 1 B, 2 C),
+    (0 A, 1 B, 2 C, 3 D),
+    (0 A, 1 B, 2 C, 3 D, 4 E),
+    (0 A, 1 B, 2 C, 3 D, 4 E, 5 F),
+    (0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G),
+    (0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H),
+    (0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I),
+    (0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J),
+    (0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J, 10 K),
+    (0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J, 10 K, 11 L),
+);
+
+/// Compares item by item, at `[index]`, if both are the same length and
+/// the order of the items matters. Otherwise lists the extra and missing
+/// items, matching them up regardless of position.
+impl<T: Diff> Diff for [T] {
+    fn diff_with(&self, other: &Self, comparison: &Comparison) -> Vec<Change> {
+        if self.len() == other.len() && !comparison.ignores_order() {
+            return self
+                .iter()
+                .zip(other)
+                .enumerate()
+                .flat_map(|(i, (actual, expected))| {
+                    comparison.diff_at(&format!("[{i}]"), actual, expected)
+                })
+                .collect();
+        }
+        let (extra, missing) = sequence_diff(self, other, |actual, expected| {
+            actual.eq_with(expected, comparison)
+        });
+        let extra = extra
+            .into_iter()
+            .map(|i| Change::extra(&self[i]).at(&format!("[{i}]")));
+        let missing = missing
+            .into_iter()
+            .map(|j| Change::missing(&other[j]).at(&format!("[{j}]")));
+        extra.chain(missing).collect()
+    }
+
+    fn eq_with(&self, other: &Self, comparison: &Comparison) -> bool {
+        if self.len() != other.len() {
+            return false;
+        }
+        if comparison.ignores_order() {
+            let (extra, _) = sequence_diff(self, other, |actual, expected| {
+                actual.eq_with(expected, comparison)
+            });
+            return extra.is_empty();
+        }
+        self.iter()
+            .zip(other)
+            .all(|(actual, expected)| actual.eq_with(expected, comparison))
+    }
+}
+
+impl<T: Diff, const N: usize> Diff for [T; N] {
+    fn diff_with(&self, other: &Self, comparison: &Comparison) -> Vec<Change> {
+        self.as_slice().diff_with(other.as_slice(), comparison)
+    }
+
+    fn eq_with(&self, other: &Self, comparison: &Comparison) -> bool {
+        self.as_slice().eq_with(other.as_slice(), comparison)
+    }
+}
+
+impl<T: Diff> Diff for Vec<T> {
+    fn diff_with(&self, other: &Self, comparison: &Comparison) -> Vec<Change> {
+        self.as_slice().diff_with(other.as_slice(), comparison)
+    }
+
+    fn eq_with(&self, other: &Self, comparison: &Comparison) -> bool {
+        self.as_slice().eq_with(other.as_slice(), comparison)
+    }
+}
+
+/// Like the [slice](slice) impl, front to back.
+impl<T: Diff> Diff for VecDeque<T> {
+    fn diff_with(&self, other: &Self, comparison: &Comparison) -> Vec<Change> {
+        let actual: Vec<&T> = self.iter().collect();
+        let expected: Vec<&T> = other.iter().collect();
+        actual.diff_with(&expected, comparison)
+    }
+
+    fn eq_with(&self, other: &Self, comparison: &Comparison) -> bool {
+        let actual: Vec<&T> = self.iter().collect();
+        let expected: Vec<&T> = other.iter().collect();
+        actual.eq_with(&expected, comparison)
+    }
+}
+
+/// Lists the extra and missing elements, in order, matching them up with
+/// [Diff::eq_with], so that ignored fields, comparators and tolerances
+/// apply to the elements too.
+impl<T: Diff + Ord> Diff for HashSet<T> {
+    fn diff_with(&self, other: &Self, comparison: &Comparison) -> Vec<Change> {
+        let (extra, missing) = set_diff(self, other, |actual, expected| {
+            actual.eq_with(expected, comparison)
+        });
+        let extra = extra.into_iter().map(Change::extra);
+        let missing = missing.into_iter().map(Change::missing);
+        extra.chain(missing).collect()
+    }
+
+    fn eq_with(&self, other: &Self, comparison: &Comparison) -> bool {
+        self.len() == other.len()
+            && set_diff(self, other, |actual, expected| {
+                actual.eq_with(expected, comparison)
+            })
+            .0
+            .is_empty()
+    }
+}
+
+/// Like the [HashSet] impl.
+impl<T: Diff + Ord> Diff for BTreeSet<T> {
+    fn diff_with(&self, other: &Self, comparison: &Comparison) -> Vec<Change> {
+        let (extra, missing) = set_diff(self, other, |actual, expected| {
+            actual.eq_with(expected, comparison)
+        });
+        let extra = extra.into_iter().map(Change::extra);
+        let missing = missing.into_iter().map(Change::missing);
+        extra.chain(missing).collect()
+    }
+
+    fn eq_with(&self, other: &Self, comparison: &Comparison) -> bool {
+        self.len() == other.len()
+            && set_diff(self, other, |actual, expected| {
+                actual.eq_with(expected, comparison)
+            })
+            .0
+            .is_empty()
+    }
+}
+
+/// Compares the values of keys in both, at `[key]`, and lists the
+/// entries of the other keys as extra or missing, in order of their keys.
+impl<K: Debug + Eq + Hash + Ord, V: Diff> Diff for HashMap<K, V> {
+    fn diff_with(&self, other: &Self, comparison: &Comparison) -> Vec<Change> {
+        let (missing_keys, extra_keys, shared) = map_diff(self, other);
+        let mut changes: Vec<(&K, Vec<Change>)> = shared
+            .into_iter()
+            .map(|(key, actual, expected)| {
+                let segment = format!("[{key:?}]");
+                (key, comparison.diff_at(&segment, actual, expected))
+            })
+            .collect();
+        changes.extend(missing_keys.into_iter().map(|key| {
+            let change = Change::missing(&other[key]).at(&format!("[{key:?}]"));
+            (key, vec![change])
+        }));
+        changes.extend(extra_keys.into_iter().map(|key| {
+            let change = Change::extra(&self[key]).at(&format!("[{key:?}]"));
+            (key, vec![change])
+        }));
+        changes.sort_by_key(|(key, _)| *key);
+        changes
+            .into_iter()
+            .flat_map(|(_, changes)| changes)
+            .collect()
+    }
+
+    fn eq_with(&self, other: &Self, comparison: &Comparison) -> bool {
+        self.len() == other.len()
+            && self.iter().all(|(key, actual)| {
+                other
+                    .get(key)
+                    .is_some_and(|expected| actual.eq_with(expected, comparison))
+            })
+    }
+}
+
+/// Like the [HashMap] impl.
+impl<K: Debug + Ord, V: Diff> Diff for BTreeMap<K, V> {
+    fn diff_with(&self, other: &Self, comparison: &Comparison) -> Vec<Change> {
+        let mut keys: Vec<&K> = self.keys().chain(other.keys()).collect();
+        keys.sort();
+        keys.dedup();
+        keys.into_iter()
+            .flat_map(|key| {
+                let segment = format!("[{key:?}]");
+                match (self.get(key), other.get(key)) {
+                    (Some(actual), Some(expected)) => {
+                        comparison.diff_at(&segment, actual, expected)
+                    }
+                    (Some(actual), None) => vec![Change::extra(actual).at(&segment)],
+                    (None, Some(expected)) => vec![Change::missing(expected).at(&segment)],
+                    (None, None) => unreachable!("key is in one of the maps"),
+                }
+            })
+            .collect()
+    }
+
+    fn eq_with(&self, other: &Self, comparison: &Comparison) -> bool {
+        self.len() == other.len()
+            && self.iter().all(|(key, actual)| {
+                other
+                    .get(key)
+                    .is_some_and(|expected| actual.eq_with(expected, comparison))
+            })
+    }
+}
+
+/// Moves `changes` into the part at `segment`.
+fn at(changes: Vec<Change>, segment: &str) -> Vec<Change> {
+    changes
+        .into_iter()
+        .map(|change| change.at(segment))
+        .collect()
+}
+
+impl<T: Diff> Assert<T> {
+    /// Assert that `self` equals `expected`, comparing them part by part
+    /// with [Diff] and listing the path to every part that differs, e.g.
+    /// `.lines[0].qty: 2 != 3`, and any extra and missing elements.
+    ///
+    /// ```
+    /// # use assert4rs::Assert;
+    /// Assert::that(vec![1, 2, 3]).is_eq_to(vec![1, 2, 3]);
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use assert4rs::Assert;
+    /// // Assertion failed: `(actual.is_eq_to(expected))`
+    /// //   Actual:   `[1, 9, 2, 3]`
+    /// //   Expected: `[1, 2, 3]`
+    /// //   Differences:
+    /// //     [1]: extra 9
+    /// Assert::that(vec![1, 9, 2, 3]).is_eq_to(vec![1, 2, 3]);
+    /// ```
+    #[track_caller]
+    pub fn is_eq_to(mut self, expected: T) -> Self {
+        let negated = self.take_negation();
+        let changes = self.actual.diff(&expected);
+        if changes.is_empty() != negated {
+            return self;
+        }
+        self.fail(self.changes_error("actual.is_eq_to(expected)", &expected, &changes, negated));
+        self
+    }
+}
+
+impl<T: Debug> Assert<T> {
+    /// The error for a failed `assertion` that `self` equals `expected`,
+    /// listing the `changes` between them unless they differ as a whole.
+    #[track_caller]
+    pub(crate) fn changes_error(
+        &self,
+        assertion: &str,
+        expected: &T,
+        changes: &[Change],
+        negated: bool,
+    ) -> AssertionError {
+        let shown = |value: &dyn Debug| {
+            let rendering = format!("{value:?}");
+            match crate::truncate::max_len() {
+                Some(max_len) => crate::truncate::window(&rendering, 0, max_len),
+                None => rendering,
+            }
+        };
+        let error = self.error(assertion).negated(negated).aligned_detail(
+            "Actual",
+            shown(&self.actual),
+            "Expected",
+        );
+        if negated {
+            error.detail("Expected", format!("not {}", shown(expected)))
+        } else if changes.iter().any(Change::is_whole) {
+            error.detail("Expected", shown(expected))
+        } else {
+            error
+                .detail("Expected", shown(expected))
+                .with_diff(describe(changes))
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::collections::{HashMap, HashSet};
 
     #[test]
     fn sequence_diff_identical_has_no_difference() {
-        let (extra, missing) = sequence_diff(&[1, 2, 3], &[1, 2, 3]);
+        let (extra, missing) = sequence_diff(&[1, 2, 3], &[1, 2, 3], PartialEq::eq);
         assert!(extra.is_empty());
         assert!(missing.is_empty());
     }
 
     #[test]
     fn sequence_diff_finds_inserted_element() {
-        let (extra, missing) = sequence_diff(&[1, 9, 2, 3], &[1, 2, 3]);
-        assert_eq!(extra, vec![&9]);
+        let (extra, missing) = sequence_diff(&[1, 9, 2, 3], &[1, 2, 3], PartialEq::eq);
+        assert_eq!(extra, vec![1]);
         assert!(missing.is_empty());
     }
 
     #[test]
     fn sequence_diff_finds_removed_element() {
-        let (extra, missing) = sequence_diff(&[1, 2], &[1, 2, 3]);
+        let (extra, missing) = sequence_diff(&[1, 2], &[1, 2, 3], PartialEq::eq);
         assert!(extra.is_empty());
-        assert_eq!(missing, vec![&3]);
+        assert_eq!(missing, vec![2]);
     }
 
     #[test]
     fn sequence_diff_finds_both_extra_and_missing() {
-        let (extra, missing) = sequence_diff(&[1, 9], &[1, 2]);
-        assert_eq!(extra, vec![&9]);
-        assert_eq!(missing, vec![&2]);
+        let (extra, missing) = sequence_diff(&[1, 9], &[1, 2], PartialEq::eq);
+        assert_eq!(extra, vec![1]);
+        assert_eq!(missing, vec![1]);
     }
 
     #[test]
     fn set_diff_identical_has_no_difference() {
         let a = HashSet::from([1, 2, 3]);
         let b = HashSet::from([1, 2, 3]);
-        let (extra, missing) = set_diff(&a, &b);
+        let (extra, missing) = set_diff(&a, &b, PartialEq::eq);
         assert!(extra.is_empty());
         assert!(missing.is_empty());
     }
@@ -114,28 +912,279 @@ mod tests {
     fn set_diff_is_sorted() {
         let actual = HashSet::from([1, 2, 3]);
         let expected = HashSet::from([1, 2, 4]);
-        let (extra, missing) = set_diff(&actual, &expected);
+        let (extra, missing) = set_diff(&actual, &expected, PartialEq::eq);
         assert_eq!(extra, vec![&3]);
         assert_eq!(missing, vec![&4]);
     }
 
     #[test]
-    fn map_diff_finds_missing_extra_and_changed() {
+    fn map_diff_finds_missing_extra_and_shared() {
         let actual = HashMap::from([("a", 1), ("b", 2), ("d", 4)]);
         let expected = HashMap::from([("a", 1), ("b", 99), ("c", 3)]);
-        let (missing_keys, extra_keys, changed) = map_diff(&actual, &expected);
+        let (missing_keys, extra_keys, shared) = map_diff(&actual, &expected);
         assert_eq!(missing_keys, vec![&"c"]);
         assert_eq!(extra_keys, vec![&"d"]);
-        assert_eq!(changed, vec![(&"b", &2, &99)]);
+        assert_eq!(shared, vec![(&"a", &1, &1), (&"b", &2, &99)]);
     }
 
     #[test]
     fn map_diff_identical_has_no_difference() {
         let a = HashMap::from([("a", 1)]);
         let b = HashMap::from([("a", 1)]);
-        let (missing_keys, extra_keys, changed) = map_diff(&a, &b);
+        let (missing_keys, extra_keys, shared) = map_diff(&a, &b);
         assert!(missing_keys.is_empty());
         assert!(extra_keys.is_empty());
-        assert!(changed.is_empty());
+        assert_eq!(shared, vec![(&"a", &1, &1)]);
+    }
+
+    fn described<T: Diff + ?Sized>(actual: &T, expected: &T) -> Vec<String> {
+        actual
+            .diff(expected)
+            .iter()
+            .map(Change::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn scalars_differ_as_a_whole() {
+        assert_eq!(described(&1, &2), ["1 != 2"]);
+        assert_eq!(described("a", "b"), ["\"a\" != \"b\""]);
+        assert!(described(&String::from("a"), &String::from("a")).is_empty());
+    }
+
+    #[test]
+    fn options_and_results_compare_their_contents() {
+        assert_eq!(described(&Some(vec![1]), &Some(vec![2])), [".0[0]: 1 != 2"]);
+        assert_eq!(described(&Some(1), &None), ["Some(1) != None"]);
+        assert_eq!(described(&Ok::<u8, u8>(1), &Err(1)), ["Ok(1) != Err(1)"]);
+        assert_eq!(described(&Err::<u8, u8>(1), &Err(2)), [".0: 1 != 2"]);
+    }
+
+    #[test]
+    fn lists_of_equal_length_compare_item_by_item() {
+        assert_eq!(
+            described(&vec![vec![1, 2], vec![3]], &vec![vec![1, 5], vec![4]]),
+            ["[0][1]: 2 != 5", "[1][0]: 3 != 4"]
+        );
+        assert_eq!(described(&[1, 2], &[2, 2]), ["[0]: 1 != 2"]);
+    }
+
+    #[test]
+    fn lists_of_different_length_list_extra_and_missing_items() {
+        assert_eq!(
+            described(&vec![1, 9, 2, 3], &vec![1, 2, 3]),
+            ["[1]: extra 9"]
+        );
+        assert_eq!(
+            described(&vec![1, 2, 9], &vec![1, 2, 3, 4]),
+            ["[2]: extra 9", "[2]: missing 3", "[3]: missing 4"]
+        );
+        assert_eq!(described(&[][..], &[1][..]), ["[0]: missing 1"]);
+    }
+
+    #[test]
+    fn extra_and_missing_items_are_named_by_their_own_index() {
+        assert_eq!(
+            described(&vec![(), (), ()], &vec![()]),
+            ["[1]: extra ()", "[2]: extra ()"]
+        );
+        let long: Vec<u32> = (0..10_000).collect();
+        let mut longer = long.clone();
+        longer.insert(5_000, 7);
+        assert_eq!(described(&longer, &long), ["[5000]: extra 7"]);
+    }
+
+    #[test]
+    fn eq_with_agrees_with_diff_with() {
+        let comparison = Comparison::new(Rules {
+            unordered: vec![String::from("*")],
+            ..Rules::default()
+        });
+        let pairs = [
+            (vec![Some((1, 2.0))], vec![Some((1, 2.0))]),
+            (vec![Some((1, 2.0))], vec![Some((1, 3.0))]),
+            (vec![None, Some((1, 2.0))], vec![Some((1, 2.0)), None]),
+            (vec![None], vec![None, None]),
+        ];
+        for (actual, expected) in &pairs {
+            for comparison in [&Comparison::default(), &comparison] {
+                assert_eq!(
+                    actual.eq_with(expected, comparison),
+                    actual.diff_with(expected, comparison).is_empty(),
+                    "{actual:?} vs {expected:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn map_changes_are_sorted_by_key() {
+        let actual = HashMap::from([("a", vec![1]), ("b", vec![2]), ("d", vec![4])]);
+        let expected = HashMap::from([("a", vec![1]), ("b", vec![99]), ("c", vec![3])]);
+        assert_eq!(
+            described(&actual, &expected),
+            [
+                "[\"b\"][0]: 2 != 99",
+                "[\"c\"]: missing [3]",
+                "[\"d\"]: extra [4]"
+            ]
+        );
+        let actual: BTreeMap<_, _> = actual.into_iter().collect();
+        let expected: BTreeMap<_, _> = expected.into_iter().collect();
+        assert_eq!(
+            described(&actual, &expected),
+            [
+                "[\"b\"][0]: 2 != 99",
+                "[\"c\"]: missing [3]",
+                "[\"d\"]: extra [4]"
+            ]
+        );
+    }
+
+    #[test]
+    fn set_changes_list_extra_then_missing_elements() {
+        assert_eq!(
+            described(&HashSet::from([1, 2, 3, 5]), &HashSet::from([4, 2, 1, 0])),
+            ["extra 3", "extra 5", "missing 0", "missing 4"]
+        );
+    }
+
+    #[test]
+    fn change_at_prefixes_path() {
+        let change = Change::changed(&1, &2).at(".qty").at("[3]").at(".lines");
+        assert_eq!(change.path(), ".lines[3].qty");
+        assert_eq!(change.actual(), Some("1"));
+        assert_eq!(change.expected(), Some("2"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Assertion failed: `(actual.is_eq_to(expected))`")]
+    fn is_eq_to_reports_generic_header_without_label() {
+        Assert::that(vec![1, 2, 3]).is_eq_to(vec![1, 2, 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Assertion failed for `x`: `(actual.is_eq_to(expected))`")]
+    fn is_eq_to_reports_label_when_named() {
+        Assert::that(vec![1, 2, 3])
+            .named("x")
+            .is_eq_to(vec![1, 2, 4]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Assertion failed: `(actual.is_eq_to(expected))`\n  Actual:   `[1, 9, 2, 3]`\n  Expected: `[1, 2, 3]`\n  Differences:\n    [1]: extra 9"
+    )]
+    fn is_eq_to_reports_full_message() {
+        Assert::that(vec![1, 9, 2, 3]).is_eq_to(vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn is_eq_to_omits_differences_for_values_differing_as_a_whole() {
+        let error = Assert::check(Some(1), |a| a.is_eq_to(None)).err().unwrap();
+        assert_eq!(error.diff(), None);
+    }
+
+    #[test]
+    fn is_eq_to_omits_missing_line_when_nothing_missing() {
+        let error = Assert::check(vec![1, 9, 2, 3], |a| a.is_eq_to(vec![1, 2, 3]))
+            .err()
+            .unwrap();
+        assert!(
+            !error.diff().unwrap().contains("missing"),
+            "unexpected missing item in: {error}"
+        );
+    }
+
+    #[test]
+    fn is_eq_to_reports_both_extra_and_missing() {
+        let error = Assert::check(vec![1, 2, 9], |a| a.is_eq_to(vec![1, 2, 3]))
+            .err()
+            .unwrap();
+        assert_eq!(error.diff(), Some("  Differences:\n    [2]: 9 != 3"));
+        let error = Assert::check(vec![1, 9], |a| a.is_eq_to(vec![1, 2, 3]))
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.diff(),
+            Some("  Differences:\n    [1]: extra 9\n    [1]: missing 2\n    [2]: missing 3")
+        );
+    }
+
+    #[test]
+    fn is_eq_to_works_on_arrays() {
+        Assert::that([1, 2, 3]).is_eq_to([1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Differences:\n    [1]: extra 9")]
+    fn is_eq_to_works_on_slices() {
+        Assert::that(&[1, 9, 2, 3][..]).is_eq_to(&[1, 2, 3][..]);
+    }
+
+    #[test]
+    fn is_eq_to_works_on_tuples_boxes_and_other_collections() {
+        Assert::that(vec![(1, "a")]).is_eq_to(vec![(1, "a")]);
+        let error = Assert::check(vec![(1, "a"), (2, "b")], |a| {
+            a.is_eq_to(vec![(1, "a"), (2, "c")])
+        })
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.diff(),
+            Some("  Differences:\n    [1].1: \"b\" != \"c\"")
+        );
+        assert_eq!(
+            described(&Box::new((1, 2)), &Box::new((1, 3))),
+            [".1: 2 != 3"]
+        );
+        assert_eq!(
+            described(&VecDeque::from([1, 2]), &VecDeque::from([1, 3])),
+            ["[1]: 2 != 3"]
+        );
+        assert_eq!(
+            described(&BTreeSet::from([1, 2]), &BTreeSet::from([2, 3])),
+            ["extra 1", "missing 3"]
+        );
+    }
+
+    #[test]
+    fn diff_as_whole_lists_differing_values_whole() {
+        #[derive(Debug, PartialEq)]
+        struct Point {
+            x: i32,
+        }
+        crate::diff_as_whole!(Point);
+        let error = Assert::check(vec![Point { x: 1 }], |a| a.is_eq_to(vec![Point { x: 2 }]))
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.diff(),
+            Some("  Differences:\n    [0]: Point { x: 1 } != Point { x: 2 }")
+        );
+    }
+
+    #[test]
+    fn is_eq_to_lists_at_most_twenty_changes() {
+        let actual: Vec<u32> = (0..25).collect();
+        let expected: Vec<u32> = (1..26).collect();
+        let error = Assert::check(actual, |a| a.is_eq_to(expected))
+            .err()
+            .unwrap();
+        assert!(
+            error
+                .diff()
+                .unwrap()
+                .ends_with("\n    [19]: 19 != 20\n    … 5 more"),
+            "{error}"
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Assertion failed: `!(actual.is_eq_to(expected))`\n  Actual:   `[1]`\n  Expected: `not [1]`"
+    )]
+    fn not_is_eq_to_reports_full_message() {
+        Assert::that(vec![1]).not().is_eq_to(vec![1]);
     }
 }
@@ -0,0 +1,501 @@
+//! Assertions on durations and points in time. Durations are shown in
+//! human units, like `1.25s` or `300ms`; [SystemTime]s as UTC dates, like
+//! `2026-10-17T09:30:00.25Z`; and [Instant]s, which have no meaning of
+//! their own, relative to when the assertion failed, like `1.25s ago`.
+
+use std::cmp::Ordering;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use crate::Assert;
+
+impl Assert<Duration> {
+    /// Assert that `self` differs from `expected` by at most `tolerance`.
+    ///
+    /// ```
+    /// # use assert4rs::Assert;
+    /// # use std::time::Duration;
+    /// Assert::that(Duration::from_millis(1020))
+    ///     .is_close_to(Duration::from_secs(1), Duration::from_millis(50));
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use assert4rs::Assert;
+    /// # use std::time::Duration;
+    /// // Assertion failed: `(actual.is_close_to(expected))`
+    /// //   Actual:    `1.25s`
+    /// //   Expected:  `1s`
+    /// //   Delta:     `250ms`
+    /// //   Tolerance: `50ms`
+    /// Assert::that(Duration::from_millis(1250))
+    ///     .is_close_to(Duration::from_secs(1), Duration::from_millis(50));
+    /// ```
+    #[track_caller]
+    pub fn is_close_to(mut self, expected: Duration, tolerance: Duration) -> Self {
+        let negated = self.take_negation();
+        let delta = self.actual.abs_diff(expected);
+        if (delta <= tolerance) == negated {
+            let expected = match negated {
+                true => format!("not {expected:?}"),
+                false => format!("{expected:?}"),
+            };
+            self.fail(
+                self.error("actual.is_close_to(expected)")
+                    .negated(negated)
+                    .aligned_detail("Actual", format!("{:?}", self.actual), "Tolerance")
+                    .aligned_detail("Expected", expected, "Tolerance")
+                    .aligned_detail("Delta", format!("{delta:?}"), "Tolerance")
+                    .detail("Tolerance", format!("{tolerance:?}")),
+            );
+        }
+        self
+    }
+
+    /// Assert that `self` is no longer than `max`.
+    ///
+    /// ```
+    /// # use assert4rs::Assert;
+    /// # use std::time::Duration;
+    /// Assert::that(Duration::from_millis(300)).is_at_most(Duration::from_millis(300));
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use assert4rs::Assert;
+    /// # use std::time::Duration;
+    /// // Assertion failed: `(actual <= max)`
+    /// //   Actual:  `1.25s`
+    /// //   Max:     `1s`
+    /// //   Over by: `250ms`
+    /// Assert::that(Duration::from_millis(1250)).is_at_most(Duration::from_secs(1));
+    /// ```
+    #[track_caller]
+    pub fn is_at_most(mut self, max: Duration) -> Self {
+        let negated = self.take_negation();
+        if (self.actual <= max) == negated {
+            let mut error = self
+                .error("actual <= max")
+                .negated(negated)
+                .aligned_detail("Actual", format!("{:?}", self.actual), "Over by")
+                .aligned_detail("Max", format!("{max:?}"), "Over by");
+            if let Some(over) = self.actual.checked_sub(max).filter(|over| !over.is_zero()) {
+                error = error.detail("Over by", format!("{over:?}"));
+            }
+            self.fail(error);
+        }
+        self
+    }
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Points in time that [`Assert::is_before`] and the other time
+/// assertions apply to: [Instant] and [SystemTime].
+pub trait TimePoint: Copy + sealed::Sealed {
+    /// The current point in time.
+    #[doc(hidden)]
+    fn now() -> Self;
+
+    /// Whether `self` comes before or after `other`, and how far apart
+    /// they are.
+    #[doc(hidden)]
+    fn offset_from(self, other: Self) -> (Ordering, Duration);
+
+    /// The point in time for failure messages.
+    #[doc(hidden)]
+    fn render(self) -> String;
+}
+
+impl sealed::Sealed for Instant {}
+
+/// Rendered relative to now, e.g. `1.25s ago` or `in 300ms`.
+impl TimePoint for Instant {
+    fn now() -> Self {
+        Instant::now()
+    }
+
+    fn offset_from(self, other: Self) -> (Ordering, Duration) {
+        match self.checked_duration_since(other) {
+            Some(offset) if offset.is_zero() => (Ordering::Equal, offset),
+            Some(offset) => (Ordering::Greater, offset),
+            None => (Ordering::Less, other.duration_since(self)),
+        }
+    }
+
+    fn render(self) -> String {
+        match self.offset_from(Instant::now()) {
+            (Ordering::Less, ago) => format!("{ago:?} ago"),
+            (Ordering::Equal, _) => String::from("now"),
+            (Ordering::Greater, ahead) => format!("in {ahead:?}"),
+        }
+    }
+}
+
+impl sealed::Sealed for SystemTime {}
+
+/// Rendered as a UTC date and time, e.g. `2026-10-17T09:30:00.25Z`.
+impl TimePoint for SystemTime {
+    fn now() -> Self {
+        SystemTime::now()
+    }
+
+    fn offset_from(self, other: Self) -> (Ordering, Duration) {
+        match self.duration_since(other) {
+            Ok(offset) if offset.is_zero() => (Ordering::Equal, offset),
+            Ok(offset) => (Ordering::Greater, offset),
+            Err(error) => (Ordering::Less, error.duration()),
+        }
+    }
+
+    fn render(self) -> String {
+        let (seconds, nanos) = match self.offset_from(UNIX_EPOCH) {
+            (Ordering::Less, before) if before.subsec_nanos() > 0 => (
+                -(before.as_secs() as i128) - 1,
+                1_000_000_000 - before.subsec_nanos(),
+            ),
+            (Ordering::Less, before) => (-(before.as_secs() as i128), 0),
+            (_, after) => (after.as_secs() as i128, after.subsec_nanos()),
+        };
+        let (year, month, day) = civil_date(seconds.div_euclid(86_400));
+        let time = seconds.rem_euclid(86_400);
+        let fraction = match nanos {
+            0 => String::new(),
+            nanos => format!(".{nanos:09}").trim_end_matches('0').to_string(),
+        };
+        format!(
+            "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}{fraction}Z",
+            time / 3600,
+            time / 60 % 60,
+            time % 60
+        )
+    }
+}
+
+/// The year, month and day of the day `days` after 1970-01-01 in the
+/// proleptic Gregorian calendar, after Howard Hinnant's `civil_from_days`.
+fn civil_date(days: i128) -> (i128, i128, i128) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + i128::from(month <= 2);
+    (year, month, day)
+}
+
+/// Renders how `ordering` and `offset` place a point in time relative to
+/// `reference`, e.g. `1.25s after other`.
+fn relative((ordering, offset): (Ordering, Duration), reference: &str) -> String {
+    match ordering {
+        Ordering::Less => format!("{offset:?} before {reference}"),
+        Ordering::Equal => format!("at the same time as {reference}"),
+        Ordering::Greater => format!("{offset:?} after {reference}"),
+    }
+}
+
+impl<T: TimePoint> Assert<T> {
+    /// Assert that `self` comes strictly before `other`.
+    ///
+    /// ```
+    /// # use assert4rs::Assert;
+    /// # use std::time::{Duration, Instant};
+    /// let start = Instant::now();
+    /// Assert::that(start).is_before(start + Duration::from_millis(1));
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use assert4rs::Assert;
+    /// # use std::time::{Duration, UNIX_EPOCH};
+    /// // Assertion failed: `(actual.is_before(other))`
+    /// //   Actual:    `1970-01-01T00:00:01.25Z`
+    /// //   Other:     `1970-01-01T00:00:00Z`
+    /// //   Actual is: `1.25s after other`
+    /// Assert::that(UNIX_EPOCH + Duration::from_millis(1250)).is_before(UNIX_EPOCH);
+    /// ```
+    #[track_caller]
+    pub fn is_before(self, other: T) -> Self {
+        self.placed("actual.is_before(other)", other, Ordering::Less)
+    }
+
+    /// Assert that `self` comes strictly after `other`.
+    ///
+    /// ```
+    /// # use assert4rs::Assert;
+    /// # use std::time::{Duration, SystemTime};
+    /// let now = SystemTime::now();
+    /// Assert::that(now).is_after(now - Duration::from_secs(60));
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use assert4rs::Assert;
+    /// # use std::time::Instant;
+    /// let now = Instant::now();
+    /// Assert::that(now).is_after(now);
+    /// ```
+    #[track_caller]
+    pub fn is_after(self, other: T) -> Self {
+        self.placed("actual.is_after(other)", other, Ordering::Greater)
+    }
+
+    /// Assert that `self` is at most `tolerance` before or after `other`.
+    ///
+    /// ```
+    /// # use assert4rs::Assert;
+    /// # use std::time::{Duration, SystemTime};
+    /// let now = SystemTime::now();
+    /// Assert::that(now + Duration::from_millis(300))
+    ///     .is_within(Duration::from_secs(1), now);
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use assert4rs::Assert;
+    /// # use std::time::{Duration, UNIX_EPOCH};
+    /// // Assertion failed: `(actual.is_within(tolerance, other))`
+    /// //   Actual:    `1970-01-01T00:00:00Z`
+    /// //   Other:     `1970-01-01T00:00:01.25Z`
+    /// //   Actual is: `1.25s before other`
+    /// //   Tolerance: `1s`
+    /// Assert::that(UNIX_EPOCH)
+    ///     .is_within(Duration::from_secs(1), UNIX_EPOCH + Duration::from_millis(1250));
+    /// ```
+    #[track_caller]
+    pub fn is_within(mut self, tolerance: Duration, other: T) -> Self {
+        let negated = self.take_negation();
+        let offset = self.actual.offset_from(other);
+        if (offset.1 <= tolerance) == negated {
+            self.fail(
+                self.error("actual.is_within(tolerance, other)")
+                    .negated(negated)
+                    .aligned_detail("Actual", self.actual.render(), "Tolerance")
+                    .aligned_detail("Other", other.render(), "Tolerance")
+                    .aligned_detail("Actual is", relative(offset, "other"), "Tolerance")
+                    .detail("Tolerance", format!("{tolerance:?}")),
+            );
+        }
+        self
+    }
+
+    /// Assert that `self` has come, that is, isn't after now.
+    ///
+    /// ```
+    /// # use assert4rs::Assert;
+    /// # use std::time::SystemTime;
+    /// Assert::that(SystemTime::UNIX_EPOCH).is_in_the_past();
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use assert4rs::Assert;
+    /// # use std::time::{Duration, Instant};
+    /// Assert::that(Instant::now() + Duration::from_secs(60)).is_in_the_past();
+    /// ```
+    #[track_caller]
+    pub fn is_in_the_past(mut self) -> Self {
+        let negated = self.take_negation();
+        let offset = self.actual.offset_from(T::now());
+        if (offset.0 != Ordering::Greater) == negated {
+            self.fail(self.relative_to_now_error("actual.is_in_the_past()", offset, negated));
+        }
+        self
+    }
+
+    /// Assert that `self` is yet to come, that is, after now.
+    ///
+    /// ```
+    /// # use assert4rs::Assert;
+    /// # use std::time::{Duration, SystemTime};
+    /// Assert::that(SystemTime::now() + Duration::from_secs(60)).is_in_the_future();
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use assert4rs::Assert;
+    /// # use std::time::Instant;
+    /// Assert::that(Instant::now()).is_in_the_future();
+    /// ```
+    #[track_caller]
+    pub fn is_in_the_future(mut self) -> Self {
+        let negated = self.take_negation();
+        let offset = self.actual.offset_from(T::now());
+        if (offset.0 == Ordering::Greater) == negated {
+            self.fail(self.relative_to_now_error("actual.is_in_the_future()", offset, negated));
+        }
+        self
+    }
+
+    /// Fails `assertion` unless `self` is placed relative to `other` as
+    /// `expected`.
+    #[track_caller]
+    fn placed(mut self, assertion: &str, other: T, expected: Ordering) -> Self {
+        let negated = self.take_negation();
+        let offset = self.actual.offset_from(other);
+        if (offset.0 == expected) == negated {
+            self.fail(
+                self.error(assertion)
+                    .negated(negated)
+                    .aligned_detail("Actual", self.actual.render(), "Actual is")
+                    .aligned_detail("Other", other.render(), "Actual is")
+                    .detail("Actual is", relative(offset, "other")),
+            );
+        }
+        self
+    }
+
+    /// The error for a failed `assertion` on where `self` is relative to
+    /// now, `offset` from it.
+    #[track_caller]
+    fn relative_to_now_error(
+        &self,
+        assertion: &str,
+        offset: (Ordering, Duration),
+        negated: bool,
+    ) -> crate::AssertionError {
+        self.error(assertion)
+            .negated(negated)
+            .aligned_detail("Actual", self.actual.render(), "Actual is")
+            .detail("Actual is", relative(offset, "now"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(millis: u64) -> SystemTime {
+        UNIX_EPOCH + Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn system_times_render_as_utc_dates() {
+        assert_eq!(UNIX_EPOCH.render(), "1970-01-01T00:00:00Z");
+        assert_eq!(at(1_250).render(), "1970-01-01T00:00:01.25Z");
+        assert_eq!(
+            (UNIX_EPOCH + Duration::new(1_792_229_400, 5)).render(),
+            "2026-10-17T09:30:00.000000005Z"
+        );
+        assert_eq!(
+            (UNIX_EPOCH + Duration::from_secs(951_782_400)).render(),
+            "2000-02-29T00:00:00Z"
+        );
+        assert_eq!(
+            (UNIX_EPOCH - Duration::from_millis(500)).render(),
+            "1969-12-31T23:59:59.5Z"
+        );
+    }
+
+    #[test]
+    fn instants_render_relative_to_now() {
+        let ahead = Instant::now() + Duration::from_secs(3600);
+        assert!(ahead.render().starts_with("in "));
+        let behind = Instant::now().checked_sub(Duration::from_secs(1));
+        if let Some(behind) = behind {
+            assert!(behind.render().ends_with(" ago"));
+        }
+    }
+
+    #[test]
+    fn duration_is_close_to_reports_delta_in_human_units() {
+        let error = Assert::check(Duration::from_millis(1250), |a| {
+            a.is_close_to(Duration::from_secs(1), Duration::from_millis(50))
+        })
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.to_string(),
+            "Assertion failed: `(actual.is_close_to(expected))`\n  \
+             Actual:    `1.25s`\n  \
+             Expected:  `1s`\n  \
+             Delta:     `250ms`\n  \
+             Tolerance: `50ms`"
+        );
+        Assert::that(Duration::from_millis(900))
+            .is_close_to(Duration::from_secs(1), Duration::from_millis(100))
+            .not()
+            .is_close_to(Duration::from_secs(1), Duration::from_millis(99));
+    }
+
+    #[test]
+    fn duration_is_at_most_reports_excess() {
+        let error = Assert::check(Duration::from_millis(1250), |a| {
+            a.is_at_most(Duration::from_secs(1))
+        })
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.to_string(),
+            "Assertion failed: `(actual <= max)`\n  \
+             Actual:  `1.25s`\n  \
+             Max:     `1s`\n  \
+             Over by: `250ms`"
+        );
+        let error = Assert::check(Duration::from_millis(300), |a| {
+            a.not().is_at_most(Duration::from_secs(1))
+        })
+        .err()
+        .unwrap();
+        assert_eq!(error.header(), "Assertion failed: `!(actual <= max)`");
+        assert_eq!(error.details().len(), 2);
+    }
+
+    #[test]
+    fn is_before_and_is_after_are_strict() {
+        Assert::that(at(1)).is_before(at(2)).is_after(at(0));
+        Assert::that(at(1))
+            .not()
+            .is_before(at(1))
+            .not()
+            .is_after(at(1));
+        let error = Assert::check(at(1_000), |a| a.is_after(at(1_000)))
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.to_string(),
+            "Assertion failed: `(actual.is_after(other))`\n  \
+             Actual:    `1970-01-01T00:00:01Z`\n  \
+             Other:     `1970-01-01T00:00:01Z`\n  \
+             Actual is: `at the same time as other`"
+        );
+    }
+
+    #[test]
+    fn is_within_reports_offset_and_tolerance() {
+        let start = Instant::now();
+        Assert::that(start + Duration::from_millis(300))
+            .is_within(Duration::from_millis(300), start)
+            .not()
+            .is_within(Duration::from_millis(299), start);
+        let error = Assert::check(at(0), |a| a.is_within(Duration::from_secs(1), at(1_250)))
+            .err()
+            .unwrap();
+        assert_eq!(error.details()[2].value(), "1.25s before other");
+        assert_eq!(error.details()[3].value(), "1s");
+    }
+
+    #[test]
+    fn past_and_future_are_relative_to_now() {
+        let hour = Duration::from_secs(3600);
+        Assert::that(SystemTime::now() - hour)
+            .is_in_the_past()
+            .not()
+            .is_in_the_future();
+        Assert::that(Instant::now() + hour)
+            .is_in_the_future()
+            .not()
+            .is_in_the_past();
+        let error = Assert::check(at(0), |a| a.is_in_the_future())
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.header(),
+            "Assertion failed: `(actual.is_in_the_future())`"
+        );
+        assert_eq!(error.details()[0].value(), "1970-01-01T00:00:00Z");
+        assert!(error.details()[1].value().ends_with("s before now"));
+    }
+}
@@ -0,0 +1,230 @@
+//! Keeps failure messages readable for huge values: long renderings are
+//! cut down to a window around the first difference, and long
+//! collections to their first few elements.
+//!
+//! Renderings are limited to [DEFAULT_MAX_LEN] characters unless the
+//! current thread chose otherwise with [set_max_len], or else the
+//! `ASSERT4RS_MAX_LEN` environment variable gives a number of
+//! characters, or `full` to turn truncation off.
+
+use std::cell::Cell;
+use std::fmt::Debug;
+
+/// Environment variable setting the maximum rendering length for
+/// threads that didn't call [set_max_len]: a number of characters, or
+/// `full` for no limit.
+pub const MAX_LEN_ENV: &str = "ASSERT4RS_MAX_LEN";
+
+/// The maximum rendering length, in characters, if neither
+/// [set_max_len] nor `ASSERT4RS_MAX_LEN` say otherwise.
+pub const DEFAULT_MAX_LEN: usize = 200;
+
+/// At most this many lines of a line diff are shown while truncation is
+/// on.
+const MAX_DIFF_LINES: usize = 100;
+
+thread_local! {
+    static MAX_LEN: Cell<Option<Option<usize>>> = const { Cell::new(None) };
+}
+
+/// Limit renderings in failures on the current thread to `max_len`
+/// characters, or don't truncate them at all with `None`.
+///
+/// ```
+/// use assert4rs::Assert;
+/// use assert4rs::truncate;
+///
+/// truncate::set_max_len(None);
+/// let panic = std::panic::catch_unwind(|| {
+///     Assert::that(vec![0; 1000]).contains(&1);
+/// })
+/// .unwrap_err();
+/// let message = panic.downcast_ref::<String>().unwrap();
+/// assert!(!message.contains("more]"));
+/// # truncate::reset_max_len();
+/// ```
+pub fn set_max_len(max_len: Option<usize>) {
+    MAX_LEN.with(|current| current.set(Some(max_len)));
+}
+
+/// Undo [set_max_len] for the current thread, falling back to the
+/// `ASSERT4RS_MAX_LEN` environment variable again.
+pub fn reset_max_len() {
+    MAX_LEN.with(|current| current.set(None));
+}
+
+/// The maximum rendering length for the current thread, `None` meaning
+/// no limit.
+pub fn max_len() -> Option<usize> {
+    if let Some(max_len) = MAX_LEN.with(Cell::get) {
+        return max_len;
+    }
+    match std::env::var(MAX_LEN_ENV).as_deref() {
+        Ok("full") => None,
+        Ok(value) => Some(value.parse().unwrap_or(DEFAULT_MAX_LEN)),
+        Err(_) => Some(DEFAULT_MAX_LEN),
+    }
+}
+
+/// Renders an elision of `count` characters.
+fn elided_chars(count: usize) -> String {
+    format!("…[{count} chars]…")
+}
+
+/// Formats `n` with `,` between groups of three digits, e.g. `9,997`.
+fn thousands(n: usize) -> String {
+    let digits = n.to_string();
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
+
+/// Where the window of a rendering that must be cut down to `max_len`
+/// characters starts, so that it shows some context before the
+/// character at `char_index` and more after it.
+pub(crate) fn window_start(char_index: usize, max_len: usize) -> usize {
+    char_index.saturating_sub(max_len / 3)
+}
+
+/// Cuts `text` down to the `max_len` characters from `start` on,
+/// replacing what's left out on either side by `…[N chars]…`.
+pub(crate) fn window(text: &str, start: usize, max_len: usize) -> String {
+    let len = text.chars().count();
+    if start == 0 && len <= max_len {
+        return text.to_string();
+    }
+    let start = start.min(len);
+    let end = (start + max_len).min(len);
+    let mut windowed = String::new();
+    if start > 0 {
+        windowed.push_str(&elided_chars(start));
+    }
+    windowed.extend(text.chars().skip(start).take(end - start));
+    if end < len {
+        windowed.push_str(&elided_chars(len - end));
+    }
+    windowed
+}
+
+/// Renders the `{:?}` of a collection's `items` between `open` and
+/// `close`, like `{:?}` of the collection itself would, but stops once
+/// the current [max_len] is exceeded and ends with `… N more`.
+fn elide<T>(
+    open: &str,
+    close: &str,
+    items: impl ExactSizeIterator<Item = T>,
+    render: impl Fn(&T) -> String,
+) -> String {
+    let max_len = max_len();
+    let total = items.len();
+    let mut rendered = String::from(open);
+    for (shown, item) in items.enumerate() {
+        if max_len.is_some_and(|max_len| rendered.chars().count() > max_len) {
+            rendered.push_str(&format!(", … {} more", thousands(total - shown)));
+            break;
+        }
+        if shown > 0 {
+            rendered.push_str(", ");
+        }
+        rendered.push_str(&render(&item));
+    }
+    rendered.push_str(close);
+    rendered
+}
+
+/// `{:?}` of a list, e.g. `[1, 2, 3, … 9,997 more]` once it's too long.
+pub(crate) fn list<T: Debug>(items: impl ExactSizeIterator<Item = T>) -> String {
+    elide("[", "]", items, |item| format!("{item:?}"))
+}
+
+/// `{:?}` of a set, e.g. `{1, 2, 3, … 9,997 more}` once it's too long.
+pub(crate) fn set<T: Debug>(items: impl ExactSizeIterator<Item = T>) -> String {
+    elide("{", "}", items, |item| format!("{item:?}"))
+}
+
+/// `{:?}` of a map, e.g. `{"a": 1, … 9,997 more}` once it's too long.
+pub(crate) fn map<K: Debug, V: Debug>(entries: impl ExactSizeIterator<Item = (K, V)>) -> String {
+    elide("{", "}", entries, |(key, value)| {
+        format!("{key:?}: {value:?}")
+    })
+}
+
+/// Cuts a line diff down to its first lines while truncation is on,
+/// ending with `…[N more lines]…`.
+pub(crate) fn diff_lines(diff: String) -> String {
+    if max_len().is_none() {
+        return diff;
+    }
+    let total = diff.lines().count();
+    if total <= MAX_DIFF_LINES {
+        return diff;
+    }
+    let mut kept: Vec<&str> = diff.lines().take(MAX_DIFF_LINES).collect();
+    let more = format!("  …[{} more lines]…", thousands(total - MAX_DIFF_LINES));
+    kept.push(&more);
+    kept.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn thousands_groups_digits() {
+        assert_eq!(thousands(7), "7");
+        assert_eq!(thousands(9997), "9,997");
+        assert_eq!(thousands(1234567), "1,234,567");
+    }
+
+    #[test]
+    fn window_elides_both_sides() {
+        let text: String = ('a'..='z').collect();
+        assert_eq!(window(&text, 10, 5), "…[10 chars]…klmno…[11 chars]…");
+    }
+
+    #[test]
+    fn window_keeps_short_text() {
+        assert_eq!(window("abc", 0, 5), "abc");
+    }
+
+    #[test]
+    fn long_list_is_elided() {
+        set_max_len(Some(20));
+        let rendered = list(0..10_000);
+        reset_max_len();
+        assert_eq!(rendered, "[0, 1, 2, 3, 4, 5, 6, 7, … 9,992 more]");
+    }
+
+    #[test]
+    fn short_collections_render_like_debug() {
+        set_max_len(Some(20));
+        assert_eq!(list([1, 2, 3].iter()), format!("{:?}", [1, 2, 3]));
+        assert_eq!(list(std::iter::empty::<i32>()), "[]");
+        assert_eq!(map([("a", 1)].into_iter()), r#"{"a": 1}"#);
+        reset_max_len();
+    }
+
+    #[test]
+    fn full_output_disables_elision() {
+        set_max_len(None);
+        let rendered = list(0..1000);
+        reset_max_len();
+        assert_eq!(rendered, format!("{:?}", (0..1000).collect::<Vec<_>>()));
+    }
+
+    #[test]
+    fn diff_lines_are_capped() {
+        let diff = (0..150)
+            .map(|n| n.to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+        let capped = diff_lines(diff);
+        assert_eq!(capped.lines().count(), MAX_DIFF_LINES + 1);
+        assert!(capped.ends_with("\n  …[50 more lines]…"), "{capped}");
+    }
+}
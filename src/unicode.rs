@@ -0,0 +1,314 @@
+//! Just enough Unicode to line a caret up under a character in a
+//! terminal (grapheme clusters and their display width), and to point
+//! out characters that are easy to miss or mistake for others.
+//!
+//! The first two are approximations built from a few hand-picked ranges rather
+//! than the full Unicode tables, which would be a lot of data for a
+//! test-only crate. They cover combining marks, CJK, emoji (including
+//! modifier, ZWJ and flag sequences) and zero-width characters; text in
+//! `{:?}` output escapes most of the rarer cases anyway.
+
+/// Characters that extend the grapheme cluster before them and take up
+/// no columns of their own: combining marks, variation selectors, emoji
+/// skin tone modifiers, tags, and zero-width format characters.
+const ZERO_WIDTH: &[(u32, u32)] = &[
+    (0x0300, 0x036F),
+    (0x0483, 0x0489),
+    (0x0591, 0x05BD),
+    (0x0610, 0x061A),
+    (0x064B, 0x065F),
+    (0x0670, 0x0670),
+    (0x06D6, 0x06DC),
+    (0x0900, 0x0903),
+    (0x093A, 0x094F),
+    (0x0E31, 0x0E31),
+    (0x0E34, 0x0E3A),
+    (0x0E47, 0x0E4E),
+    (0x1AB0, 0x1AFF),
+    (0x1DC0, 0x1DFF),
+    (0x200B, 0x200F),
+    (0x2060, 0x2064),
+    (0x20D0, 0x20FF),
+    (0x302A, 0x302F),
+    (0x3099, 0x309A),
+    (0xFE00, 0xFE0F),
+    (0xFE20, 0xFE2F),
+    (0xFEFF, 0xFEFF),
+    (0x1F3FB, 0x1F3FF),
+    (0xE0000, 0xE007F),
+    (0xE0100, 0xE01EF),
+];
+
+/// Characters that take up two terminal columns: CJK, Hangul,
+/// full-width forms and emoji.
+const WIDE: &[(u32, u32)] = &[
+    (0x1100, 0x115F),
+    (0x231A, 0x231B),
+    (0x23E9, 0x23EC),
+    (0x23F0, 0x23F0),
+    (0x23F3, 0x23F3),
+    (0x25FD, 0x25FE),
+    (0x2614, 0x2615),
+    (0x2648, 0x2653),
+    (0x267F, 0x267F),
+    (0x2693, 0x2693),
+    (0x26A1, 0x26A1),
+    (0x26AA, 0x26AB),
+    (0x26BD, 0x26BE),
+    (0x26C4, 0x26C5),
+    (0x26CE, 0x26CE),
+    (0x26D4, 0x26D4),
+    (0x26EA, 0x26EA),
+    (0x26F2, 0x26F5),
+    (0x26FA, 0x26FD),
+    (0x2705, 0x2705),
+    (0x270A, 0x270B),
+    (0x2728, 0x2728),
+    (0x274C, 0x274C),
+    (0x274E, 0x274E),
+    (0x2753, 0x2755),
+    (0x2757, 0x2757),
+    (0x2795, 0x2797),
+    (0x27B0, 0x27B0),
+    (0x27BF, 0x27BF),
+    (0x2B1B, 0x2B1C),
+    (0x2B50, 0x2B50),
+    (0x2B55, 0x2B55),
+    (0x2E80, 0x303E),
+    (0x3041, 0x33FF),
+    (0x3400, 0x4DBF),
+    (0x4E00, 0x9FFF),
+    (0xA000, 0xA4CF),
+    (0xA960, 0xA97F),
+    (0xAC00, 0xD7A3),
+    (0xF900, 0xFAFF),
+    (0xFE10, 0xFE19),
+    (0xFE30, 0xFE6F),
+    (0xFF00, 0xFF60),
+    (0xFFE0, 0xFFE6),
+    (0x16FE0, 0x16FE4),
+    (0x17000, 0x18CFF),
+    (0x1B000, 0x1B2FF),
+    (0x1F004, 0x1F004),
+    (0x1F0CF, 0x1F0CF),
+    (0x1F18E, 0x1F18E),
+    (0x1F191, 0x1F19A),
+    (0x1F1E6, 0x1F1FF),
+    (0x1F200, 0x1F2FF),
+    (0x1F300, 0x1F64F),
+    (0x1F680, 0x1F6FF),
+    (0x1F7E0, 0x1F7EB),
+    (0x1F900, 0x1F9FF),
+    (0x1FA70, 0x1FAFF),
+    (0x20000, 0x2FFFD),
+    (0x30000, 0x3FFFD),
+];
+
+const ZERO_WIDTH_JOINER: char = '\u{200D}';
+const VARIATION_SELECTOR_16: char = '\u{FE0F}';
+
+fn in_ranges(c: char, ranges: &[(u32, u32)]) -> bool {
+    let c = c as u32;
+    ranges
+        .binary_search_by(|&(start, end)| {
+            if end < c {
+                std::cmp::Ordering::Less
+            } else if start > c {
+                std::cmp::Ordering::Greater
+            } else {
+                std::cmp::Ordering::Equal
+            }
+        })
+        .is_ok()
+}
+
+fn is_regional_indicator(c: char) -> bool {
+    ('\u{1F1E6}'..='\u{1F1FF}').contains(&c)
+}
+
+/// Number of terminal columns `c` takes up on its own.
+pub(crate) fn char_width(c: char) -> usize {
+    if c == ZERO_WIDTH_JOINER || in_ranges(c, ZERO_WIDTH) || c.is_control() {
+        0
+    } else if in_ranges(c, WIDE) {
+        2
+    } else {
+        1
+    }
+}
+
+/// Splits `text` into grapheme clusters: a base character with the
+/// combining marks, modifiers and joined characters that follow it,
+/// a pair of regional indicators (a flag), or `\r\n`.
+pub(crate) fn graphemes(text: &str) -> Vec<&str> {
+    let mut clusters = Vec::new();
+    let mut start = 0;
+    let mut previous: Option<char> = None;
+    let mut regional_indicators = 0;
+    for (index, c) in text.char_indices() {
+        let extends = match previous {
+            None => false,
+            Some('\r') => c == '\n',
+            Some(ZERO_WIDTH_JOINER) => true,
+            Some(p) if is_regional_indicator(p) && is_regional_indicator(c) => {
+                regional_indicators % 2 == 1
+            }
+            Some(_) => c == ZERO_WIDTH_JOINER || in_ranges(c, ZERO_WIDTH),
+        };
+        if !extends && index > start {
+            clusters.push(&text[start..index]);
+            start = index;
+        }
+        regional_indicators = if is_regional_indicator(c) {
+            regional_indicators + 1
+        } else {
+            0
+        };
+        previous = Some(c);
+    }
+    if start < text.len() {
+        clusters.push(&text[start..]);
+    }
+    clusters
+}
+
+/// Number of terminal columns a grapheme cluster takes up: that of its
+/// base character, or two for an emoji forced into emoji presentation.
+fn grapheme_width(grapheme: &str) -> usize {
+    let width = grapheme.chars().map(char_width).next().unwrap_or(0);
+    if grapheme.contains(VARIATION_SELECTOR_16) {
+        width.max(2)
+    } else {
+        width
+    }
+}
+
+/// Number of terminal columns `text` takes up.
+pub(crate) fn display_width(text: &str) -> usize {
+    graphemes(text).into_iter().map(grapheme_width).sum()
+}
+
+/// The visible stand-in for a whitespace character that is hard to
+/// spot or tell apart in a failure message, e.g. `'·'` for a space.
+pub(crate) fn marker(c: char) -> Option<char> {
+    match c {
+        ' ' => Some('·'),
+        '\t' => Some('→'),
+        '\r' => Some('␍'),
+        '\n' => Some('␊'),
+        _ => None,
+    }
+}
+
+/// Names of characters that are invisible or look like an ordinary
+/// space (or nothing at all).
+const INVISIBLE_NAMES: &[(char, &str)] = &[
+    ('\u{00A0}', "no-break space"),
+    ('\u{00AD}', "soft hyphen"),
+    ('\u{034F}', "combining grapheme joiner"),
+    ('\u{180E}', "mongolian vowel separator"),
+    ('\u{2002}', "en space"),
+    ('\u{2003}', "em space"),
+    ('\u{2007}', "figure space"),
+    ('\u{2009}', "thin space"),
+    ('\u{200A}', "hair space"),
+    ('\u{200B}', "zero-width space"),
+    ('\u{200C}', "zero-width non-joiner"),
+    ('\u{200D}', "zero-width joiner"),
+    ('\u{200E}', "left-to-right mark"),
+    ('\u{200F}', "right-to-left mark"),
+    ('\u{2028}', "line separator"),
+    ('\u{2029}', "paragraph separator"),
+    ('\u{202F}', "narrow no-break space"),
+    ('\u{205F}', "medium mathematical space"),
+    ('\u{2060}', "word joiner"),
+    ('\u{3000}', "ideographic space"),
+    ('\u{FEFF}', "zero-width no-break space"),
+];
+
+/// Whether `c` is invisible or looks like an ordinary space without
+/// being one: any whitespace that has no [marker], control characters,
+/// and zero-width or direction-changing format characters.
+pub(crate) fn is_invisible(c: char) -> bool {
+    ((c.is_whitespace() || c.is_control()) && marker(c).is_none())
+        || INVISIBLE_NAMES.iter().any(|&(named, _)| named == c)
+        || ('\u{202A}'..='\u{202E}').contains(&c)
+        || ('\u{2061}'..='\u{2064}').contains(&c)
+        || ('\u{2066}'..='\u{2069}').contains(&c)
+}
+
+/// The name of an [invisible](is_invisible) character, if it's one of
+/// the common ones.
+pub(crate) fn invisible_name(c: char) -> Option<&'static str> {
+    INVISIBLE_NAMES
+        .iter()
+        .find(|&&(named, _)| named == c)
+        .map(|&(_, name)| name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ascii_is_one_column_per_char() {
+        assert_eq!(display_width("hello"), 5);
+    }
+
+    #[test]
+    fn cjk_and_emoji_are_two_columns() {
+        assert_eq!(display_width("日本"), 4);
+        assert_eq!(display_width("a🎉b"), 4);
+    }
+
+    #[test]
+    fn combining_marks_join_their_base() {
+        assert_eq!(graphemes("e\u{301}x"), ["e\u{301}", "x"]);
+        assert_eq!(display_width("e\u{301}x"), 2);
+    }
+
+    #[test]
+    fn emoji_sequences_are_one_grapheme() {
+        assert_eq!(graphemes("👍🏽!"), ["👍🏽", "!"]);
+        assert_eq!(
+            graphemes("👨\u{200d}👩\u{200d}👧"),
+            ["👨\u{200d}👩\u{200d}👧"]
+        );
+        assert_eq!(display_width("👨\u{200d}👩\u{200d}👧"), 2);
+    }
+
+    #[test]
+    fn regional_indicators_pair_into_flags() {
+        assert_eq!(graphemes("🇳🇱🇧🇪"), ["🇳🇱", "🇧🇪"]);
+        assert_eq!(display_width("🇳🇱🇧🇪"), 4);
+    }
+
+    #[test]
+    fn variation_selector_forces_emoji_width() {
+        assert_eq!(display_width("\u{2764}\u{FE0F}"), 2);
+    }
+
+    #[test]
+    fn crlf_is_one_grapheme() {
+        assert_eq!(graphemes("a\r\nb"), ["a", "\r\n", "b"]);
+    }
+
+    #[test]
+    fn whitespace_has_markers() {
+        assert_eq!(marker(' '), Some('·'));
+        assert_eq!(marker('\t'), Some('→'));
+        assert_eq!(marker('\r'), Some('␍'));
+        assert_eq!(marker('a'), None);
+    }
+
+    #[test]
+    fn lookalike_and_zero_width_characters_are_invisible() {
+        assert!(is_invisible('\u{00A0}'));
+        assert!(is_invisible('\u{200D}'));
+        assert!(is_invisible('\u{202E}'));
+        assert!(is_invisible('\u{7}'));
+        assert!(!is_invisible(' '));
+        assert!(!is_invisible('a'));
+        assert_eq!(invisible_name('\u{00A0}'), Some("no-break space"));
+    }
+}
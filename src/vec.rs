@@ -1,6 +1,72 @@
 use crate::Assert;
+use crate::matcher::Matcher;
 use std::fmt::Debug;
 
+/// Fails unless every one of `items` matches `matcher`, naming the first
+/// item that doesn't; `negated`, fails if they all do.
+#[track_caller]
+fn all_match<A, T: Debug>(
+    assert: &Assert<A>,
+    negated: bool,
+    items: &[T],
+    matcher: impl Matcher<T>,
+) {
+    let mismatch = items
+        .iter()
+        .enumerate()
+        .find(|(_, item)| !matcher.matches(item));
+    if mismatch.is_some() == negated {
+        return;
+    }
+    let error = assert
+        .error("actual.iter().all(matcher)")
+        .negated(negated)
+        .aligned_detail("Actual", crate::truncate::list(items.iter()), "Expected");
+    assert.fail(match mismatch {
+        Some((index, item)) => error
+            .aligned_detail(
+                "Expected",
+                format!("each {}", matcher.describe()),
+                "Mismatch",
+            )
+            .aligned_detail(
+                "Mismatch",
+                format!("[{index}]: {}", matcher.describe_mismatch(item)),
+                "Expected",
+            ),
+        None => error.detail("Expected", format!("not each {}", matcher.describe())),
+    });
+}
+
+/// Fails unless at least one of `items` matches `matcher`; `negated`,
+/// fails naming the first one that does.
+#[track_caller]
+fn any_match<A, T: Debug>(
+    assert: &Assert<A>,
+    negated: bool,
+    items: &[T],
+    matcher: impl Matcher<T>,
+) {
+    let found = items.iter().position(|item| matcher.matches(item));
+    if found.is_some() != negated {
+        return;
+    }
+    let error = assert
+        .error("actual.iter().any(matcher)")
+        .negated(negated)
+        .aligned_detail("Actual", crate::truncate::list(items.iter()), "Expected");
+    assert.fail(match found {
+        Some(index) => error
+            .aligned_detail(
+                "Expected",
+                format!("no item {}", matcher.describe()),
+                "Found at",
+            )
+            .aligned_detail("Found at", format!("[{index}]"), "Expected"),
+        None => error.detail("Expected", format!("any {}", matcher.describe())),
+    });
+}
+
 /// DSL for [Vec].
 impl<T> Assert<Vec<T>> {
     /// Assert that the actual vector contains a specific `expected`
@@ -11,34 +77,50 @@ impl<T> Assert<Vec<T>> {
     /// Assert::that(vec![1, 2, 3]).contains(&2);
     /// ```
     #[track_caller]
-    pub fn contains(self, expected: &T) -> Self
+    pub fn contains(mut self, expected: &T) -> Self
     where
         T: PartialEq + Debug,
     {
-        assert!(
-            self.actual.contains(expected),
-            "{}\n  Actual:   `{:?}`\n  Expected to contain: `{:?}`\n  Missing: `{:?}`",
-            self.header("actual.contains(expected)"),
-            self.actual,
-            expected,
-            expected,
-        );
+        let negated = self.take_negation();
+        if self.actual.contains(expected) == negated {
+            let error = self
+                .error("actual.contains(expected)")
+                .negated(negated)
+                .aligned_detail(
+                    "Actual",
+                    crate::truncate::list(self.actual.iter()),
+                    "Expected",
+                );
+            self.fail(if negated {
+                let index = self.actual.iter().position(|item| item == expected);
+                error
+                    .detail("Expected not to contain", format!("{expected:?}"))
+                    .detail("Found at", format!("[{}]", index.unwrap_or_default()))
+            } else {
+                error
+                    .detail("Expected to contain", format!("{expected:?}"))
+                    .detail("Missing", format!("{expected:?}"))
+            });
+        }
         self
     }
 
-    /// Returns an [Assert] for a value from the [Vec].
+    /// Returns an [Assert] for a value from the [Vec], appending
+    /// `[index]` to the label path.
     ///
     /// ```
     /// # use assert4rs::{Assert};
     /// Assert::that(vec!['a', 'b', 'c']).get(1).is_some('b');
     /// Assert::that(vec!['a', 'b', 'c']).get(5).is_none();
     /// ```
-    pub fn get(mut self, index: usize) -> Assert<Option<T>> {
-        if index < self.actual.len() {
-            Assert::that(Some(self.actual.swap_remove(index)))
-        } else {
-            Assert::that(None)
-        }
+    pub fn get(self, index: usize) -> Assert<Option<T>> {
+        self.map_suffixed(&format!("[{index}]"), |mut actual| {
+            if index < actual.len() {
+                Some(actual.swap_remove(index))
+            } else {
+                None
+            }
+        })
     }
 
     /// Assert that the actual vector is empty.
@@ -53,16 +135,18 @@ impl<T> Assert<Vec<T>> {
     /// Assert::that(vec![1, 2, 3]).is_empty();
     /// ```
     #[track_caller]
-    pub fn is_empty(self) -> Self
+    pub fn is_empty(mut self) -> Self
     where
         T: Debug,
     {
-        assert!(
-            self.actual.is_empty(),
-            "{}\n  Actual: `{:?}`",
-            self.header("actual.is_empty()"),
-            self.actual,
-        );
+        let negated = self.take_negation();
+        if self.actual.is_empty() == negated {
+            self.fail(
+                self.error("actual.is_empty()")
+                    .negated(negated)
+                    .detail("Actual", crate::truncate::list(self.actual.iter())),
+            );
+        }
         self
     }
 
@@ -78,14 +162,68 @@ impl<T> Assert<Vec<T>> {
     /// Assert::that(vec![1, 2, 3]).has_length(2);
     /// ```
     #[track_caller]
-    pub fn has_length(self, expected: usize) -> Self {
-        assert!(
-            self.actual.len() == expected,
-            "{}\n  Actual:   `{}`\n  Expected: `{}`",
-            self.header("actual.len() == expected"),
-            self.actual.len(),
-            expected,
-        );
+    pub fn has_length(mut self, expected: usize) -> Self {
+        let negated = self.take_negation();
+        if (self.actual.len() == expected) == negated {
+            let expected = if negated {
+                format!("not {expected}")
+            } else {
+                expected.to_string()
+            };
+            self.fail(
+                self.error("actual.len() == expected")
+                    .negated(negated)
+                    .aligned_detail("Actual", self.actual.len().to_string(), "Expected")
+                    .detail("Expected", expected),
+            );
+        }
+        self
+    }
+
+    /// Assert that every item of the actual vector matches `matcher`.
+    ///
+    /// ```
+    /// # use assert4rs::Assert;
+    /// use assert4rs::matcher::gt;
+    /// Assert::that(vec![4, 5, 6]).all_match(gt(3));
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use assert4rs::Assert;
+    /// use assert4rs::matcher::gt;
+    /// Assert::that(vec![4, 2, 6]).all_match(gt(3));
+    /// ```
+    #[track_caller]
+    pub fn all_match(mut self, matcher: impl Matcher<T>) -> Self
+    where
+        T: Debug,
+    {
+        let negated = self.take_negation();
+        all_match(&self, negated, &self.actual, matcher);
+        self
+    }
+
+    /// Assert that at least one item of the actual vector matches
+    /// `matcher`.
+    ///
+    /// ```
+    /// # use assert4rs::Assert;
+    /// use assert4rs::matcher::gt;
+    /// Assert::that(vec![1, 5, 2]).any_match(gt(3));
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use assert4rs::Assert;
+    /// use assert4rs::matcher::gt;
+    /// Assert::that(vec![1, 2]).any_match(gt(3));
+    /// ```
+    #[track_caller]
+    pub fn any_match(mut self, matcher: impl Matcher<T>) -> Self
+    where
+        T: Debug,
+    {
+        let negated = self.take_negation();
+        any_match(&self, negated, &self.actual, matcher);
         self
     }
 }
@@ -103,18 +241,31 @@ impl<T> Assert<&Vec<T>> {
     /// Assert::that(&v).contains(&2);
     /// ```
     #[track_caller]
-    pub fn contains(self, expected: &T) -> Self
+    pub fn contains(mut self, expected: &T) -> Self
     where
         T: PartialEq + Debug,
     {
-        assert!(
-            self.actual.contains(expected),
-            "{}\n  Actual:   `{:?}`\n  Expected to contain: `{:?}`\n  Missing: `{:?}`",
-            self.header("actual.contains(expected)"),
-            self.actual,
-            expected,
-            expected,
-        );
+        let negated = self.take_negation();
+        if self.actual.contains(expected) == negated {
+            let error = self
+                .error("actual.contains(expected)")
+                .negated(negated)
+                .aligned_detail(
+                    "Actual",
+                    crate::truncate::list(self.actual.iter()),
+                    "Expected",
+                );
+            self.fail(if negated {
+                let index = self.actual.iter().position(|item| item == expected);
+                error
+                    .detail("Expected not to contain", format!("{expected:?}"))
+                    .detail("Found at", format!("[{}]", index.unwrap_or_default()))
+            } else {
+                error
+                    .detail("Expected to contain", format!("{expected:?}"))
+                    .detail("Missing", format!("{expected:?}"))
+            });
+        }
         self
     }
 
@@ -132,16 +283,18 @@ impl<T> Assert<&Vec<T>> {
     /// Assert::that(&v).is_empty();
     /// ```
     #[track_caller]
-    pub fn is_empty(self) -> Self
+    pub fn is_empty(mut self) -> Self
     where
         T: Debug,
     {
-        assert!(
-            self.actual.is_empty(),
-            "{}\n  Actual: `{:?}`",
-            self.header("actual.is_empty()"),
-            self.actual,
-        );
+        let negated = self.take_negation();
+        if self.actual.is_empty() == negated {
+            self.fail(
+                self.error("actual.is_empty()")
+                    .negated(negated)
+                    .detail("Actual", crate::truncate::list(self.actual.iter())),
+            );
+        }
         self
     }
 
@@ -159,14 +312,68 @@ impl<T> Assert<&Vec<T>> {
     /// Assert::that(&v).has_length(2);
     /// ```
     #[track_caller]
-    pub fn has_length(self, expected: usize) -> Self {
-        assert!(
-            self.actual.len() == expected,
-            "{}\n  Actual:   `{}`\n  Expected: `{}`",
-            self.header("actual.len() == expected"),
-            self.actual.len(),
-            expected,
-        );
+    pub fn has_length(mut self, expected: usize) -> Self {
+        let negated = self.take_negation();
+        if (self.actual.len() == expected) == negated {
+            let expected = if negated {
+                format!("not {expected}")
+            } else {
+                expected.to_string()
+            };
+            self.fail(
+                self.error("actual.len() == expected")
+                    .negated(negated)
+                    .aligned_detail("Actual", self.actual.len().to_string(), "Expected")
+                    .detail("Expected", expected),
+            );
+        }
+        self
+    }
+
+    /// Assert that every item of the actual vector matches `matcher`.
+    ///
+    /// ```
+    /// # use assert4rs::Assert;
+    /// use assert4rs::matcher::gt;
+    /// Assert::that(&vec![4, 5, 6]).all_match(gt(3));
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use assert4rs::Assert;
+    /// use assert4rs::matcher::gt;
+    /// Assert::that(&vec![4, 2, 6]).all_match(gt(3));
+    /// ```
+    #[track_caller]
+    pub fn all_match(mut self, matcher: impl Matcher<T>) -> Self
+    where
+        T: Debug,
+    {
+        let negated = self.take_negation();
+        all_match(&self, negated, self.actual, matcher);
+        self
+    }
+
+    /// Assert that at least one item of the actual vector matches
+    /// `matcher`.
+    ///
+    /// ```
+    /// # use assert4rs::Assert;
+    /// use assert4rs::matcher::gt;
+    /// Assert::that(&vec![1, 5, 2]).any_match(gt(3));
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use assert4rs::Assert;
+    /// use assert4rs::matcher::gt;
+    /// Assert::that(&vec![1, 2]).any_match(gt(3));
+    /// ```
+    #[track_caller]
+    pub fn any_match(mut self, matcher: impl Matcher<T>) -> Self
+    where
+        T: Debug,
+    {
+        let negated = self.take_negation();
+        any_match(&self, negated, self.actual, matcher);
         self
     }
 }
@@ -241,4 +448,73 @@ mod tests {
         let v = vec![1, 2, 3];
         Assert::that(&v).named("x").has_length(5);
     }
+
+    #[test]
+    #[should_panic(expected = "\n  Actual:   `[0, 1, 2, 3, 4, 5, 6, 7, … 9,992 more]`\n")]
+    fn contains_elides_long_vec() {
+        crate::truncate::set_max_len(Some(20));
+        let v: Vec<i32> = (0..10_000).collect();
+        Assert::that(v).contains(&-1);
+    }
+
+    #[test]
+    fn all_match_names_first_mismatching_item() {
+        let error = Assert::check(vec![4, 2, 1], |a| a.all_match(crate::matcher::gt(3)))
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.to_string().lines().skip(1).collect::<Vec<_>>(),
+            [
+                "  Actual:   `[4, 2, 1]`",
+                "  Expected: `each greater than 3`",
+                "  Mismatch: `[1]: was 2`",
+            ]
+        );
+    }
+
+    #[test]
+    fn matchers_are_reusable_across_assertions() {
+        let positive = crate::matcher::gt(0);
+        let v = vec![1, 2, 3];
+        Assert::that(&v).all_match(&positive).any_match(&positive);
+        Assert::that(v).all_match(&positive);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Assertion failed: `!(actual.contains(expected))`\n  Actual:   `[1, 2, 3]`\n  Expected not to contain: `2`\n  Found at: `[1]`"
+    )]
+    fn not_contains_reports_full_message() {
+        Assert::that(vec![1, 2, 3]).not().contains(&2);
+    }
+
+    #[test]
+    fn not_flips_vec_assertions() {
+        let v = vec![1, 2, 3];
+        Assert::that(&v)
+            .not()
+            .is_empty()
+            .not()
+            .has_length(2)
+            .not()
+            .all_match(crate::matcher::gt(1))
+            .not()
+            .any_match(crate::matcher::gt(3));
+        Assert::that(v).not().is_eq_to(vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn not_any_match_reports_matching_item() {
+        let error = Assert::check(vec![1, 5], |a| a.not().any_match(crate::matcher::gt(3)))
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.to_string().lines().skip(1).collect::<Vec<_>>(),
+            [
+                "  Actual:   `[1, 5]`",
+                "  Expected: `no item greater than 3`",
+                "  Found at: `[1]`",
+            ]
+        );
+    }
 }
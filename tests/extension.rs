@@ -0,0 +1,89 @@
+//! Domain assertions defined outside the crate, the way users write them.
+
+use assert4rs::{Assert, assertion};
+
+#[derive(Debug, PartialEq)]
+enum Status {
+    Open,
+    Paid,
+}
+
+#[derive(Debug)]
+struct Order {
+    status: Status,
+    total: u32,
+}
+
+assertion! {
+    trait OrderAssertions for Order {
+        fn is_paid(&self) -> self.status == Status::Paid; "order is paid"
+        fn has_total(&self, total: u32) -> self.total == total; "order has the given total"
+    }
+}
+
+fn open_order() -> Order {
+    Order {
+        status: Status::Open,
+        total: 42,
+    }
+}
+
+#[test]
+fn passing_assertions_chain() {
+    Assert::that(open_order())
+        .not()
+        .is_paid()
+        .has_total(42)
+        .map(|order| order.total)
+        .is(42);
+}
+
+#[test]
+fn failure_has_standard_layout() {
+    let error = Assert::check(open_order(), |a| a.named("order").is_paid())
+        .err()
+        .unwrap();
+    assert_eq!(
+        error.to_string(),
+        "Assertion failed for `order`: `(self.status == Status::Paid)`\
+         \n  Actual:   `Order { status: Open, total: 42 }`\
+         \n  Expected: `order is paid`"
+    );
+    assert_eq!(error.location().file(), file!());
+}
+
+#[test]
+fn failure_lists_arguments() {
+    let error = Assert::check(open_order(), |a| a.has_total(7))
+        .err()
+        .unwrap();
+    assert_eq!(
+        error.to_string(),
+        "Assertion failed: `(self.total == total)`\
+         \n  Actual:   `Order { status: Open, total: 42 }`\
+         \n  Expected: `order has the given total`\
+         \n  total:    `7`"
+    );
+}
+
+#[test]
+fn negated_failure_reads_not() {
+    let error = Assert::check(open_order(), |a| a.not().has_total(42))
+        .err()
+        .unwrap();
+    assert_eq!(error.header(), "Assertion failed: `!(self.total == total)`");
+    assert_eq!(error.expected(), Some("not order has the given total"));
+}
+
+#[test]
+fn custom_failures_are_collected_in_soft_mode() {
+    let report = std::panic::catch_unwind(|| {
+        Assert::soft(|s| {
+            s.that(open_order()).is_paid();
+            s.that(open_order()).has_total(1);
+        })
+    })
+    .unwrap_err();
+    let report = report.downcast_ref::<String>().unwrap();
+    assert!(report.starts_with("2 soft assertions failed:"), "{report}");
+}
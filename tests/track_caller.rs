@@ -1,10 +1,14 @@
 use std::panic;
 use std::sync::{Arc, Mutex};
 
-use assert4rs::Assert;
+use assert4rs::{Assert, assert_that};
+
+/// The panic hook is process-wide, so tests that swap it take turns.
+static HOOK: Mutex<()> = Mutex::new(());
 
 #[test]
 fn panic_location_points_to_call_site_not_library_internals() {
+    let _hook = HOOK.lock().unwrap();
     let captured: Arc<Mutex<Option<String>>> = Arc::new(Mutex::new(None));
     let captured_clone = captured.clone();
 
@@ -32,3 +36,40 @@ fn panic_location_points_to_call_site_not_library_internals() {
         "expected panic location in this test file, got: {file}"
     );
 }
+
+#[test]
+fn assert_that_macro_reports_call_site_location() {
+    let _hook = HOOK.lock().unwrap();
+    let captured: Arc<Mutex<Option<(String, u32)>>> = Arc::new(Mutex::new(None));
+    let captured_clone = captured.clone();
+
+    let previous_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        if let Some(location) = info.location() {
+            *captured_clone.lock().unwrap() = Some((location.file().to_string(), location.line()));
+        }
+    }));
+
+    let expected_line = line!() + 2;
+    let result = panic::catch_unwind(|| {
+        assert_that!(1 + 1).is(3);
+    });
+
+    panic::set_hook(previous_hook);
+
+    let message = result.unwrap_err();
+    assert_eq!(
+        message.downcast_ref::<String>().map(|m| m.lines().next()),
+        Some(Some("Assertion failed for `1 + 1`: `(actual == expected)`"))
+    );
+    let (file, line) = captured
+        .lock()
+        .unwrap()
+        .clone()
+        .expect("panic location was captured");
+    assert!(
+        file.ends_with("track_caller.rs"),
+        "expected panic location in this test file, got: {file}"
+    );
+    assert_eq!(line, expected_line);
+}